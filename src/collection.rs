@@ -10,22 +10,25 @@ use serde::{
     Deserialize, Deserializer, Serialize,
 };
 
-use crate::model::{AgeGroup, Athlete, Club, Entry, Event, Fee, Meet, Session};
+use crate::model::{
+    self, AgeGroup, Athlete, Club, Entry, Event, Fee, Heat, Judge, Meet, Official, Record,
+    RecordList, Relay, RelayPosition, Session, Split, TimeStandard, TimeStandardList,
+    TimeStandardRef,
+};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Collection<I> {
     collection_key: &'static str,
     item_key: &'static str,
     items: Vec<I>,
 }
 
-impl<I> Default for Collection<I> {
+impl<I> Default for Collection<I>
+where
+    Collection<I>: From<Vec<I>>,
+{
     fn default() -> Self {
-        Self {
-            collection_key: "UNKNOWN",
-            item_key: "UNKNOWN",
-            items: Vec::new(),
-        }
+        Vec::new().into()
     }
 }
 
@@ -89,6 +92,26 @@ impl From<Vec<Fee>> for Collection<Fee> {
     }
 }
 
+impl From<Vec<Heat>> for Collection<Heat> {
+    fn from(value: Vec<Heat>) -> Self {
+        Collection {
+            collection_key: "HEATS",
+            item_key: "HEAT",
+            items: value,
+        }
+    }
+}
+
+impl From<Vec<Judge>> for Collection<Judge> {
+    fn from(value: Vec<Judge>) -> Self {
+        Collection {
+            collection_key: "JUDGES",
+            item_key: "JUDGE",
+            items: value,
+        }
+    }
+}
+
 impl From<Vec<Meet>> for Collection<Meet> {
     fn from(value: Vec<Meet>) -> Self {
         Collection {
@@ -99,6 +122,66 @@ impl From<Vec<Meet>> for Collection<Meet> {
     }
 }
 
+impl From<Vec<Official>> for Collection<Official> {
+    fn from(value: Vec<Official>) -> Self {
+        Collection {
+            collection_key: "OFFICIALS",
+            item_key: "OFFICIAL",
+            items: value,
+        }
+    }
+}
+
+impl From<Vec<Record>> for Collection<Record> {
+    fn from(value: Vec<Record>) -> Self {
+        Collection {
+            collection_key: "RECORDS",
+            item_key: "RECORD",
+            items: value,
+        }
+    }
+}
+
+impl From<Vec<RecordList>> for Collection<RecordList> {
+    fn from(value: Vec<RecordList>) -> Self {
+        Collection {
+            collection_key: "RECORDLISTS",
+            item_key: "RECORDLIST",
+            items: value,
+        }
+    }
+}
+
+impl From<Vec<Relay>> for Collection<Relay> {
+    fn from(value: Vec<Relay>) -> Self {
+        Collection {
+            collection_key: "RELAYS",
+            item_key: "RELAY",
+            items: value,
+        }
+    }
+}
+
+impl From<Vec<RelayPosition>> for Collection<RelayPosition> {
+    fn from(value: Vec<RelayPosition>) -> Self {
+        Collection {
+            collection_key: "RELAYPOSITIONS",
+            item_key: "RELAYPOSITION",
+            items: value,
+        }
+    }
+}
+
+impl From<Vec<model::Result>> for Collection<model::Result> {
+    fn from(value: Vec<model::Result>) -> Self {
+        Collection {
+            collection_key: "RESULTS",
+            item_key: "RESULT",
+            items: value,
+        }
+    }
+}
+
 impl From<Vec<Session>> for Collection<Session> {
     fn from(value: Vec<Session>) -> Self {
         Collection {
@@ -109,6 +192,46 @@ impl From<Vec<Session>> for Collection<Session> {
     }
 }
 
+impl From<Vec<Split>> for Collection<Split> {
+    fn from(value: Vec<Split>) -> Self {
+        Collection {
+            collection_key: "SPLITS",
+            item_key: "SPLIT",
+            items: value,
+        }
+    }
+}
+
+impl From<Vec<TimeStandard>> for Collection<TimeStandard> {
+    fn from(value: Vec<TimeStandard>) -> Self {
+        Collection {
+            collection_key: "TIMESTANDARDS",
+            item_key: "TIMESTANDARD",
+            items: value,
+        }
+    }
+}
+
+impl From<Vec<TimeStandardList>> for Collection<TimeStandardList> {
+    fn from(value: Vec<TimeStandardList>) -> Self {
+        Collection {
+            collection_key: "TIMESTANDARDLISTS",
+            item_key: "TIMESTANDARDLIST",
+            items: value,
+        }
+    }
+}
+
+impl From<Vec<TimeStandardRef>> for Collection<TimeStandardRef> {
+    fn from(value: Vec<TimeStandardRef>) -> Self {
+        Collection {
+            collection_key: "TIMESTANDARDREFS",
+            item_key: "TIMESTANDARDREF",
+            items: value,
+        }
+    }
+}
+
 impl<I> AsRef<Vec<I>> for Collection<I> {
     fn as_ref(&self) -> &Vec<I> {
         &self.items
@@ -1,10 +1,36 @@
+use fast_xml::DeError;
 use thiserror::Error;
+use zip::result::ZipError;
 
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("{0}")]
     FileExtension(FileExtensionError),
 
+    #[error("{0}")]
+    Io(std::io::Error),
+
+    #[error("{0}")]
+    Zip(ZipError),
+
+    #[error("{0}")]
+    Xml(XmlError),
+
+    #[error("{0}")]
+    Serialize(DeError),
+
+    #[error("The document is not valid {0}.")]
+    Encoding(&'static str),
+
+    #[error("Unsupported encoding {0}.")]
+    UnsupportedEncoding(String),
+
+    #[error("The archive does not contain any file.")]
+    EmptyArchive,
+
+    #[error("{0}")]
+    Entry(EntryError),
+
     #[error("{0}")]
     Custom(String),
 }
@@ -18,8 +44,76 @@ pub enum FileExtensionError {
     UnsupportedExtension(String),
 }
 
+/// An entry refused while building an entries document.
+#[derive(Error, Debug, PartialEq)]
+pub enum EntryError {
+    #[error("The invitation does not contain any meet.")]
+    NoMeet,
+
+    #[error("Event {0} does not exist.")]
+    UnknownEvent(u32),
+
+    #[error("Event {0} was not selected.")]
+    EventNotSelected(u32),
+
+    #[error("Event {event_id} is a relay event, athlete {athlete_id} can not enter it alone.")]
+    RelayEvent { athlete_id: u32, event_id: u32 },
+
+    #[error("Event {event_id} is not a relay event.")]
+    IndividualEvent { event_id: u32 },
+
+    #[error("Event {event_id} is not open to the gender of competitor {competitor}.")]
+    Gender { competitor: u32, event_id: u32 },
+
+    #[error("Event {event_id} is not open to the sport class of athlete {athlete_id}.")]
+    Handicap { athlete_id: u32, event_id: u32 },
+
+    #[error("Competitor {competitor} does not fit any age group of event {event_id}.")]
+    AgeGroup { competitor: u32, event_id: u32 },
+}
+
+/// A document that could not be deserialized, located by line, column and element path.
+#[derive(Error, Debug)]
+#[error("{message} (line {line}, column {column}, element {path})")]
+pub struct XmlError {
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+    pub path: String,
+}
+
 impl From<FileExtensionError> for Error {
     fn from(value: FileExtensionError) -> Self {
         Self::FileExtension(value)
     }
 }
+
+impl From<EntryError> for Error {
+    fn from(value: EntryError) -> Self {
+        Self::Entry(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<ZipError> for Error {
+    fn from(value: ZipError) -> Self {
+        Self::Zip(value)
+    }
+}
+
+impl From<fast_xml::Error> for Error {
+    fn from(value: fast_xml::Error) -> Self {
+        Self::Serialize(value.into())
+    }
+}
+
+impl From<XmlError> for Error {
+    fn from(value: XmlError) -> Self {
+        Self::Xml(value)
+    }
+}
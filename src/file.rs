@@ -1,10 +1,18 @@
-use std::{fs::File, io::Read, path::Path};
+use std::{
+    cell::Cell,
+    fs::File,
+    io::{Cursor, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
 
-use zip::ZipArchive;
+use fast_xml::de::Deserializer;
+use serde::Deserialize;
+use zip::{write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
 
 use crate::{
-    error::{Error, FileExtensionError},
+    error::{Error, FileExtensionError, XmlError},
     model::Lenex,
+    serialization::{encoding, location::CountingReader},
     Result,
 };
 
@@ -30,20 +38,546 @@ impl TryFrom<&Path> for SupportedFileExtension {
     }
 }
 
+const ZIP_MAGIC: &[u8; 4] = b"PK\x03\x04";
+
 pub fn open_path(path: &Path) -> Result<Lenex> {
-    let mut content = String::new();
-    let mut file = File::open(path).unwrap();
+    open(path, false)
+}
 
-    match SupportedFileExtension::try_from(path)? {
-        SupportedFileExtension::Lef => {
-            let _ = file.read_to_string(&mut content);
-        }
+/// Like [`open_path`], keeping the attributes and elements unknown to the model so that saving
+/// the document writes them back.
+pub fn open_path_lossless(path: &Path) -> Result<Lenex> {
+    open(path, true)
+}
+
+fn open(path: &Path, lossless: bool) -> Result<Lenex> {
+    let extension = SupportedFileExtension::try_from(path)?;
+    let file = File::open(path)?;
+
+    match extension {
+        SupportedFileExtension::Lef => read_lef(file, lossless),
+        SupportedFileExtension::Lxf => read_lxf(file, lossless),
+    }
+}
+
+/// Reads a `.lef` document or a `.lxf` archive, telling them apart by their first bytes.
+///
+/// The document starts at the current position of `reader`.
+pub fn from_reader<R: Read + Seek>(reader: R) -> Result<Lenex> {
+    read(reader, false)
+}
+
+fn read<R: Read + Seek>(mut reader: R, lossless: bool) -> Result<Lenex> {
+    let start = reader.stream_position()?;
+    let mut magic = Vec::with_capacity(ZIP_MAGIC.len());
+    reader
+        .by_ref()
+        .take(ZIP_MAGIC.len() as u64)
+        .read_to_end(&mut magic)?;
+    reader.seek(SeekFrom::Start(start))?;
+
+    if magic == ZIP_MAGIC {
+        read_lxf(reader, lossless)
+    } else {
+        read_lef(reader, lossless)
+    }
+}
+
+/// Reads a `.lef` document or a `.lxf` archive held in memory.
+pub fn from_bytes(bytes: &[u8]) -> Result<Lenex> {
+    from_reader(Cursor::new(bytes))
+}
+
+/// Like [`from_bytes`], keeping the attributes and elements unknown to the model so that
+/// serializing the document writes them back.
+pub fn from_bytes_lossless(bytes: &[u8]) -> Result<Lenex> {
+    read(Cursor::new(bytes), true)
+}
+
+/// Reads a `.lxf` archive held in memory.
+pub fn from_lxf_bytes(bytes: &[u8]) -> Result<Lenex> {
+    read_lxf(Cursor::new(bytes), false)
+}
+
+pub fn save_path(lenex: &Lenex, path: &Path) -> Result<()> {
+    save_path_with_encoding(lenex, path, "UTF-8")
+}
+
+/// Like [`save_path`], writing the document in the encoding named by `label`. See
+/// [`Lenex::xml_with_encoding`].
+pub fn save_path_with_encoding(lenex: &Lenex, path: &Path, label: &str) -> Result<()> {
+    let extension = SupportedFileExtension::try_from(path)?;
+    let file = File::create(path)?;
+
+    match extension {
+        SupportedFileExtension::Lef => write_lef_with_encoding(lenex, file, label),
         SupportedFileExtension::Lxf => {
-            let mut archive = ZipArchive::new(file).unwrap();
-            let mut zip_archive = archive.by_index(0).unwrap();
-            let _ = zip_archive.read_to_string(&mut content);
+            let name = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or("lenex");
+
+            write_lxf_with_encoding(lenex, file, &format!("{name}.lef"), label)
         }
-    };
+    }
+}
+
+/// Writes `lenex` as an uncompressed `.lef` document.
+pub fn write_lef<W: Write>(lenex: &Lenex, writer: W) -> Result<()> {
+    write_lef_with_encoding(lenex, writer, "UTF-8")
+}
+
+/// Like [`write_lef`], writing the document in the encoding named by `label`.
+pub fn write_lef_with_encoding<W: Write>(lenex: &Lenex, mut writer: W, label: &str) -> Result<()> {
+    writer.write_all(&lenex.xml_with_encoding(label)?)?;
+
+    Ok(())
+}
+
+/// Writes `lenex` as a `.lxf` archive holding a single `.lef` document named `name`.
+pub fn write_lxf<W: Write + Seek>(lenex: &Lenex, writer: W, name: &str) -> Result<()> {
+    write_lxf_with_encoding(lenex, writer, name, "UTF-8")
+}
+
+/// Like [`write_lxf`], writing the document in the encoding named by `label`.
+pub fn write_lxf_with_encoding<W: Write + Seek>(
+    lenex: &Lenex,
+    writer: W,
+    name: &str,
+    label: &str,
+) -> Result<()> {
+    let mut archive = ZipWriter::new(writer);
+    archive.start_file(
+        name,
+        FileOptions::default().compression_method(CompressionMethod::Deflated),
+    )?;
+    write_lef_with_encoding(lenex, &mut archive, label)?;
+    archive.finish()?;
+
+    Ok(())
+}
+
+fn read_lef<R: Read>(mut reader: R, lossless: bool) -> Result<Lenex> {
+    let mut content = Vec::new();
+    reader.read_to_end(&mut content)?;
+
+    parse(&encoding::decode(&content)?, lossless)
+}
+
+/// Reads the `.lef` document of an archive, or its first file when none has that extension.
+fn read_lxf<R: Read + Seek>(reader: R, lossless: bool) -> Result<Lenex> {
+    let mut archive = ZipArchive::new(reader)?;
+    if archive.is_empty() {
+        return Err(Error::EmptyArchive);
+    }
+
+    let name = archive
+        .file_names()
+        .find(|name| {
+            Path::new(name)
+                .extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case("lef"))
+        })
+        .map(String::from);
+
+    match name {
+        Some(name) => read_lef(archive.by_name(&name)?, lossless),
+        None => read_lef(archive.by_index(0)?, lossless),
+    }
+}
+
+fn parse(content: &str, lossless: bool) -> Result<Lenex> {
+    let consumed = Cell::new(0);
+    let mut deserializer =
+        Deserializer::from_reader(CountingReader::new(content.as_bytes(), &consumed));
+
+    let mut lenex = Lenex::deserialize(&mut deserializer)
+        .map_err(|e| XmlError::locate(e, content.as_bytes(), consumed.get()))?;
+    if lossless {
+        lenex.preserve_extras(content).map_err(Error::Serialize)?;
+    }
+
+    Ok(lenex)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, ops::Deref, path::PathBuf, process};
+
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::model::{Event, Meet, Session, SwimStyle};
+
+    /// A file of the temporary directory unique to the test run, removed once the test is done.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str) -> Self {
+            Self(env::temp_dir().join(format!("lenex-rs-{}-{name}", process::id())))
+        }
+    }
+
+    impl Deref for TempFile {
+        type Target = Path;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl AsRef<Path> for TempFile {
+        fn as_ref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    fn lenex() -> Lenex {
+        let mut lenex = Lenex::new();
+        lenex.constructor.registration = "lenex-rs".into();
+
+        lenex
+    }
+
+    #[test]
+    fn save_and_open_lef() {
+        let path = TempFile::new("save.lef");
+        let lenex = lenex();
+
+        assert!(save_path(&lenex, &path).is_ok());
+        assert!(fs::read_to_string(&path)
+            .unwrap()
+            .starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?><LENEX"));
+
+        let result = open_path(&path);
+        assert!(result.is_ok());
+        assert_eq!(lenex, result.unwrap());
+    }
+
+    #[test]
+    fn save_and_open_lxf() {
+        let path = TempFile::new("save.lxf");
+        let lenex = lenex();
+
+        assert!(save_path(&lenex, &path).is_ok());
+
+        let mut archive = ZipArchive::new(File::open(&path).unwrap()).unwrap();
+        assert_eq!(1, archive.len());
+        assert_eq!(
+            format!("lenex-rs-{}-save.lef", process::id()),
+            archive.by_index(0).unwrap().name()
+        );
+
+        let result = open_path(&path);
+        assert!(result.is_ok());
+        assert_eq!(lenex, result.unwrap());
+    }
+
+    #[test]
+    fn save_unsupported_extension() {
+        let path = TempFile::new("save.xml");
+
+        let result = save_path(&lenex(), &path);
+        assert!(matches!(result, Err(Error::FileExtension(_))));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn read_lef_bytes() {
+        let lenex = lenex();
+        let xml = lenex.xml().unwrap();
+
+        let result = from_bytes(xml.as_bytes());
+        assert!(result.is_ok());
+        assert_eq!(lenex, result.unwrap());
+
+        let result = from_lxf_bytes(xml.as_bytes());
+        assert!(matches!(result, Err(Error::Zip(_))));
+    }
+
+    #[test]
+    fn read_lxf_bytes() {
+        let lenex = lenex();
+        let mut buffer = Cursor::new(Vec::new());
+        assert!(write_lxf(&lenex, &mut buffer, "meet.lef").is_ok());
 
-    Ok(Lenex::try_from(content).unwrap())
+        let result = from_bytes(buffer.get_ref());
+        assert!(result.is_ok());
+        assert_eq!(lenex, result.unwrap());
+
+        let result = from_lxf_bytes(buffer.get_ref());
+        assert!(result.is_ok());
+        assert_eq!(lenex, result.unwrap());
+    }
+
+    #[test]
+    fn read_lxf_picks_lef_entry() {
+        let lenex = lenex();
+        let mut buffer = Cursor::new(Vec::new());
+
+        let mut archive = ZipWriter::new(&mut buffer);
+        archive
+            .start_file("README.txt", FileOptions::default())
+            .unwrap();
+        archive.write_all(b"Entries for the meet").unwrap();
+        archive
+            .start_file("meet.LEF", FileOptions::default())
+            .unwrap();
+        write_lef(&lenex, &mut archive).unwrap();
+        archive.finish().unwrap();
+        drop(archive);
+
+        let result = from_reader(Cursor::new(buffer.into_inner()));
+        assert!(result.is_ok());
+        assert_eq!(lenex, result.unwrap());
+    }
+
+    #[test]
+    fn read_from_position() {
+        let lenex = lenex();
+        let mut lef = b"--boundary\n".to_vec();
+        lef.extend(lenex.xml().unwrap().as_bytes());
+
+        let mut reader = Cursor::new(lef);
+        reader.set_position(11);
+        let result = from_reader(reader);
+        assert!(result.is_ok());
+        assert_eq!(lenex, result.unwrap());
+
+        let mut lxf = b"--boundary\n".to_vec();
+        let mut buffer = Cursor::new(Vec::new());
+        write_lxf(&lenex, &mut buffer, "meet.lef").unwrap();
+        lxf.extend(buffer.into_inner());
+
+        let mut reader = Cursor::new(lxf);
+        reader.set_position(11);
+        let result = from_reader(reader);
+        assert!(result.is_ok());
+        assert_eq!(lenex, result.unwrap());
+    }
+
+    #[test]
+    fn read_latin1_bytes() {
+        let mut lenex = lenex();
+        lenex.constructor.name = "Équipe Montréal".into();
+
+        let bytes = lenex.xml_with_encoding("ISO-8859-1");
+        assert!(bytes.is_ok());
+        let bytes = bytes.unwrap();
+        assert!(bytes.starts_with(b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?>"));
+        assert!(!bytes.is_ascii());
+
+        let result = from_bytes(&bytes);
+        assert!(result.is_ok());
+        assert_eq!(lenex, result.unwrap());
+
+        lenex.constructor.name = "Équipe Montréal €".into();
+        let bytes = lenex.xml_with_encoding("ISO-8859-1").unwrap();
+        assert!(bytes.starts_with(b"<?xml version=\"1.0\" encoding=\"windows-1252\"?>"));
+        assert_eq!(lenex, from_bytes(&bytes).unwrap());
+
+        let bytes = lenex.xml_with_encoding("UTF-16").unwrap();
+        assert!(bytes.starts_with(b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+
+        let result = lenex.xml_with_encoding("EBCDIC");
+        assert!(matches!(result, Err(Error::UnsupportedEncoding(_))));
+    }
+
+    #[test]
+    fn save_latin1_lxf() {
+        let path = TempFile::new("latin1.lxf");
+        let mut lenex = lenex();
+        lenex.constructor.name = "Équipe Montréal".into();
+
+        assert!(save_path_with_encoding(&lenex, &path, "ISO-8859-1").is_ok());
+
+        let mut archive = ZipArchive::new(File::open(&path).unwrap()).unwrap();
+        let mut bytes = Vec::new();
+        archive
+            .by_index(0)
+            .unwrap()
+            .read_to_end(&mut bytes)
+            .unwrap();
+        assert!(bytes.starts_with(b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?>"));
+        assert!(bytes.windows(2).any(|w| w == b"\xc9q"));
+
+        let result = open_path(&path);
+        assert!(result.is_ok());
+        assert_eq!(lenex, result.unwrap());
+    }
+
+    #[test]
+    fn read_lossless_bytes() {
+        let mut lenex = lenex();
+        lenex.meets.push(Meet::new(
+            "Championships".into(),
+            "CAN".into(),
+            "Montréal".into(),
+            vec![Session::new(
+                1,
+                NaiveDate::from_ymd_opt(2024, 3, 2).unwrap(),
+                vec![Event::new(1, 1, SwimStyle::default())],
+            )],
+        ));
+        let xml = lenex
+            .xml()
+            .unwrap()
+            .replacen(
+                "<LENEX version=\"3.0\"",
+                "<LENEX version=\"3.0\" generator=\"vendor\"",
+                1,
+            )
+            .replacen("<EVENTS>", "<VENDOR id=\"1\"><NOTE/></VENDOR><EVENTS>", 1)
+            .replacen(
+                "number=\"1\"><SWIMSTYLE",
+                "number=\"1\" vendor:code=\"A &amp; B\"><SWIMSTYLE",
+                1,
+            );
+
+        let result = from_bytes_lossless(xml.as_bytes());
+        assert!(result.is_ok());
+        let lossless = result.unwrap();
+
+        let session = &lossless.meets[0].sessions()[0];
+        let (_, element) = &session.extra().elements[0];
+        assert_eq!("<VENDOR id=\"1\"><NOTE/></VENDOR>", element);
+        let (_, name, value) = &session.events()[0].extra().attributes[0];
+        assert_eq!(("vendor:code", "A & B"), (name.as_str(), value.as_str()));
+        assert_eq!(xml, lossless.xml().unwrap());
+
+        let lossy = from_bytes(xml.as_bytes()).unwrap();
+        assert!(lossy.meets[0].sessions()[0].extra().is_empty());
+        assert_eq!(lenex.xml().unwrap(), lossy.xml().unwrap());
+        assert!(lossless.lossless() && !lossy.lossless());
+        assert_eq!(lossy, lossless);
+    }
+
+    #[test]
+    fn read_lossless_positions() {
+        let mut lenex = lenex();
+        lenex.meets.push(Meet::new(
+            "Championships".into(),
+            "CAN".into(),
+            "Montréal".into(),
+            vec![],
+        ));
+        let xml = lenex
+            .xml()
+            .unwrap()
+            .replacen(" city=", " vendor:id=\"7\" city=", 1)
+            .replacen("<CLUBS/>", "<VENDOR/><CLUBS/><NOTE/>", 1);
+
+        // The empty officials are not written back, the vendor element stays before the clubs.
+        let read = xml.replacen("<VENDOR/>", "<OFFICIALS/><VENDOR/>", 1);
+        let lossless = from_bytes_lossless(read.as_bytes()).unwrap();
+
+        let (position, _, _) = &lossless.meets[0].extra().attributes[0];
+        assert_eq!(vec!["name".to_string()], position.after);
+        assert_eq!(xml, lossless.xml().unwrap());
+    }
+
+    #[test]
+    fn read_empty_bytes() {
+        let result = from_bytes(&[]);
+        assert!(matches!(result, Err(Error::Xml(_))));
+    }
+
+    #[test]
+    fn write_lxf_to_memory() {
+        let mut buffer = Cursor::new(Vec::new());
+
+        assert!(write_lxf(&lenex(), &mut buffer, "meet.lef").is_ok());
+        assert!(buffer.get_ref().starts_with(b"PK\x03\x04"));
+    }
+
+    #[test]
+    fn open_missing_file() {
+        let path = TempFile::new("missing.lef");
+
+        let result = open_path(&path);
+        assert!(matches!(result, Err(Error::Io(_))));
+    }
+
+    #[test]
+    fn open_unsupported_extension() {
+        let result = open_path(Path::new("meet.xml"));
+        assert!(matches!(
+            result,
+            Err(Error::FileExtension(
+                FileExtensionError::UnsupportedExtension(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn open_invalid_archive() {
+        let path = TempFile::new("invalid.lxf");
+        fs::write(&path, "not a zip").unwrap();
+
+        let result = open_path(&path);
+        assert!(matches!(result, Err(Error::Zip(_))));
+    }
+
+    #[test]
+    fn open_empty_archive() {
+        let path = TempFile::new("empty.lxf");
+        ZipWriter::new(File::create(&path).unwrap())
+            .finish()
+            .unwrap();
+
+        let result = open_path(&path);
+        assert!(matches!(result, Err(Error::EmptyArchive)));
+    }
+
+    #[test]
+    fn open_malformed_document() {
+        let path = TempFile::new("malformed.lxf");
+        let mut zip = ZipWriter::new(File::create(&path).unwrap());
+        zip.start_file("lenex-rs-malformed.lef", FileOptions::default())
+            .unwrap();
+        zip.write_all(
+            br#"<?xml version="1.0" encoding="UTF-8"?>
+<LENEX version="3.0">
+  <CONSTRUCTOR name="lenex-rs" registration="lenex-rs" version="0.1.0">
+    <CONTACT name="lenex-rs" country="CA" email="lenex@example.com" internet="https://github.com/francisbr/lenex-rs"/>
+  </CONSTRUCTOR>
+  <MEETS>
+    <MEET name="Meet" city="Montreal" nation="CAN">
+      <SESSIONS/>
+      <CLUBS>
+        <CLUB clubid="1" name="Club">
+          <ATHLETES>
+            <ATHLETE athleteid="1" firstname="Jane" lastname="Doe" gender="F" birthdate="2010-01-01">
+              <ENTRIES>
+                <ENTRY eventid="abc"/>
+              </ENTRIES>
+            </ATHLETE>
+          </ATHLETES>
+        </CLUB>
+      </CLUBS>
+    </MEET>
+  </MEETS>
+</LENEX>"#,
+        )
+        .unwrap();
+        zip.finish().unwrap();
+
+        let result = open_path(&path);
+        assert!(matches!(result, Err(Error::Xml(_))));
+
+        if let Err(Error::Xml(error)) = result {
+            assert_eq!(14, error.line);
+            assert_eq!(17, error.column);
+            assert_eq!(
+                "MEETS/MEET[0]/CLUBS/CLUB[0]/ATHLETES/ATHLETE[0]/ENTRIES/ENTRY[0]",
+                error.path
+            );
+        }
+    }
 }
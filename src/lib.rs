@@ -9,7 +9,14 @@ mod error;
 mod file;
 pub mod model;
 mod serialization;
+mod stream;
 
 type Result<R> = std::result::Result<R, error::Error>;
 
-pub use file::open_path;
+pub use error::{EntryError, Error, FileExtensionError, XmlError};
+pub use file::{
+    from_bytes, from_bytes_lossless, from_lxf_bytes, from_reader, open_path, open_path_lossless,
+    save_path, save_path_with_encoding, write_lef, write_lef_with_encoding, write_lxf,
+    write_lxf_with_encoding,
+};
+pub use stream::{LenexReader, MeetContext};
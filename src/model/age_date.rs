@@ -1,27 +1,85 @@
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate};
 use serde::{Deserialize, Serialize};
 use strum::IntoStaticStr;
 
-#[derive(Serialize, Deserialize, PartialEq, Default, Debug)]
+use crate::serialization::preserve::Preserve;
+
+use super::{birth_date::BirthDate, extra::Extra};
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
 pub struct AgeDate {
     pub value: NaiveDate,
     pub r#type: AgeDateType,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+impl AgeDate {
+    /// The age of an athlete born on `birth_date`, following the rules of the age date type.
+    ///
+    /// `None` when only the year of birth is known and the rules need the full birth date.
+    pub fn age(&self, birth_date: BirthDate) -> Option<u16> {
+        let season_start_year = match self.value.month() {
+            9.. => self.value.year(),
+            _ => self.value.year() - 1,
+        };
+
+        let age = match self.r#type {
+            AgeDateType::Year => self.value.year() - birth_date.year(),
+            AgeDateType::Date => completed_years(birth_date.date()?, self.value),
+            AgeDateType::Por => match NaiveDate::from_ymd_opt(season_start_year, 9, 1) {
+                Some(season_start) => completed_years(birth_date.date()?, season_start),
+                None => 0,
+            },
+            AgeDateType::CanFnq => season_start_year - birth_date.year(),
+            AgeDateType::Lux => season_start_year + 1 - birth_date.year(),
+        };
+
+        Some(age.clamp(0, u16::MAX.into()) as u16)
+    }
+}
+
+fn completed_years(birth_date: NaiveDate, date: NaiveDate) -> i32 {
+    date.years_since(birth_date).map_or(0, |years| years as i32)
 }
 
 #[derive(Serialize, Deserialize, IntoStaticStr, PartialEq, Default, Debug, Clone)]
 #[serde(rename_all = "UPPERCASE", into = "&str")]
 #[strum(serialize_all = "UPPERCASE")]
 pub enum AgeDateType {
+    /// The age reached during the year of the age date, only the year of birth counting.
     Year,
+
+    /// The exact age on the age date.
     #[default]
     Date,
+
+    /// The exact age on the first day of the season of the age date, seasons starting on
+    /// September 1st.
     Por,
+
+    /// The age reached during the year the season of the age date starts, seasons starting on
+    /// September 1st.
     #[serde(rename = "CAN.FNQ")]
     #[strum(serialize = "CAN.FNQ")]
     CanFnq,
+
+    /// The age reached during the year the season of the age date ends, seasons starting on
+    /// September 1st.
     Lux,
 }
 
+impl Preserve for AgeDate {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,4 +101,47 @@ mod tests {
 
         assert_eq!(AgeDateType::CanFnq, result.unwrap());
     }
+
+    #[test]
+    fn age() {
+        let birth_date = NaiveDate::from_ymd_opt(2010, 10, 15).unwrap().into();
+        let age = |r#type, year, month, day| {
+            AgeDate {
+                value: NaiveDate::from_ymd_opt(year, month, day).unwrap(),
+                r#type,
+                ..Default::default()
+            }
+            .age(birth_date)
+            .unwrap()
+        };
+
+        assert_eq!(13, age(AgeDateType::Year, 2023, 2, 11));
+        assert_eq!(12, age(AgeDateType::Date, 2023, 2, 11));
+        assert_eq!(13, age(AgeDateType::Date, 2023, 10, 15));
+        assert_eq!(11, age(AgeDateType::Por, 2023, 2, 11));
+        assert_eq!(12, age(AgeDateType::Por, 2023, 10, 1));
+        assert_eq!(12, age(AgeDateType::CanFnq, 2023, 2, 11));
+        assert_eq!(13, age(AgeDateType::CanFnq, 2023, 10, 1));
+        assert_eq!(13, age(AgeDateType::Lux, 2023, 2, 11));
+        assert_eq!(14, age(AgeDateType::Lux, 2023, 10, 1));
+        assert_eq!(0, age(AgeDateType::Date, 2009, 1, 1));
+    }
+
+    #[test]
+    fn age_from_year() {
+        let age = |r#type| {
+            AgeDate {
+                value: NaiveDate::from_ymd_opt(2023, 10, 1).unwrap(),
+                r#type,
+                ..Default::default()
+            }
+            .age(BirthDate::Year(2010))
+        };
+
+        assert_eq!(Some(13), age(AgeDateType::Year));
+        assert_eq!(Some(13), age(AgeDateType::CanFnq));
+        assert_eq!(Some(14), age(AgeDateType::Lux));
+        assert_eq!(None, age(AgeDateType::Date));
+        assert_eq!(None, age(AgeDateType::Por));
+    }
 }
@@ -1,22 +1,30 @@
 use serde::{Deserialize, Serialize};
 
+use crate::serialization::preserve::Preserve;
+
 pub use self::calculate::Calculate;
 
-use super::gender::Gender;
+use super::{
+    age_date::AgeDate,
+    athlete::Athlete,
+    extra::Extra,
+    gender::Gender,
+    handicap::{SportClass, SportClasses},
+};
 
 mod calculate;
 
-#[derive(Serialize, Deserialize, PartialEq, Default, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
 #[serde(rename = "AGEGROUP")]
 pub struct AgeGroup {
     #[serde(rename = "agegroupid")]
     pub id: u32,
 
     #[serde(rename = "agemin", default, with = "crate::serialization::serde_age")]
-    pub age_min: Option<u8>,
+    pub age_min: Option<u16>,
 
     #[serde(rename = "agemax", default, with = "crate::serialization::serde_age")]
-    pub age_max: Option<u8>,
+    pub age_max: Option<u16>,
 
     #[serde(default)]
     pub gender: Gender,
@@ -25,6 +33,115 @@ pub struct AgeGroup {
     pub calculate: Calculate,
 
     pub name: Option<String>,
+
+    /// The sport classes of the athletes this age group is restricted to.
+    pub handicap: Option<SportClasses>,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+impl AgeGroup {
+    pub fn new(id: u32, age_min: Option<u16>, age_max: Option<u16>) -> Self {
+        Self {
+            id,
+            age_min,
+            age_max,
+            ..Default::default()
+        }
+    }
+
+    /// Whether `age` is within the bounds of this age group, a missing bound being open.
+    pub fn includes_age(&self, age: u16) -> bool {
+        self.age_min.is_none_or(|min| min <= age) && self.age_max.is_none_or(|max| age <= max)
+    }
+
+    /// Whether the athlete can swim in this age group, by gender and age on `age_date`.
+    ///
+    /// For total age groups the bounds apply to the sum of the ages of a relay, so only the
+    /// gender of a single athlete is checked. See [`AgeGroup::accepts_relay`].
+    pub fn accepts(&self, athlete: &Athlete, age_date: &AgeDate) -> bool {
+        self.accepts_gender(athlete.gender())
+            && match self.calculate {
+                Calculate::Single => self.includes_athlete(athlete, age_date),
+                Calculate::Total => true,
+            }
+    }
+
+    /// Whether an athlete of sport class `class` can swim in this age group.
+    pub fn accepts_sport_class(&self, class: Option<SportClass>) -> bool {
+        self.handicap
+            .as_ref()
+            .is_none_or(|classes| classes.admits(class))
+    }
+
+    /// Whether the relay swum by `athletes` fits this age group: every athlete must be within
+    /// the bounds, or for total age groups the sum of their ages.
+    pub fn accepts_relay(&self, athletes: &[&Athlete], age_date: &AgeDate) -> bool {
+        if !athletes
+            .iter()
+            .all(|athlete| self.accepts_gender(athlete.gender()))
+        {
+            return false;
+        }
+
+        match self.calculate {
+            Calculate::Single => athletes
+                .iter()
+                .all(|athlete| self.includes_athlete(athlete, age_date)),
+            Calculate::Total => {
+                let total: Option<u32> = athletes
+                    .iter()
+                    .map(|athlete| athlete_age(athlete, age_date).map(u32::from))
+                    .sum();
+
+                match total {
+                    Some(total) => {
+                        self.age_min.is_none_or(|min| u32::from(min) <= total)
+                            && self.age_max.is_none_or(|max| total <= u32::from(max))
+                    }
+                    None => self.is_open(),
+                }
+            }
+        }
+    }
+
+    /// Athletes of unknown age only fit age groups open to every age.
+    fn includes_athlete(&self, athlete: &Athlete, age_date: &AgeDate) -> bool {
+        match athlete_age(athlete, age_date) {
+            Some(age) => self.includes_age(age),
+            None => self.is_open(),
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        self.age_min.is_none() && self.age_max.is_none()
+    }
+
+    fn accepts_gender(&self, gender: &Gender) -> bool {
+        match self.gender {
+            Gender::All | Gender::Mixed => true,
+            ref own => own == gender,
+        }
+    }
+}
+
+/// The age of an athlete on `age_date`, unknown without a birth date or with only a year of
+/// birth when the age date needs the full date.
+fn athlete_age(athlete: &Athlete, age_date: &AgeDate) -> Option<u16> {
+    athlete
+        .birth_date()
+        .and_then(|birth_date| age_date.age(birth_date))
+}
+
+impl Preserve for AgeGroup {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
 }
 
 #[cfg(test)]
@@ -62,7 +179,7 @@ mod tests {
         let age_groups = result.unwrap();
 
         assert_eq!(2, age_groups.len());
-        assert_eq!(123, age_groups.get(0).unwrap().id);
+        assert_eq!(123, age_groups.first().unwrap().id);
     }
 
     #[test]
@@ -77,7 +194,7 @@ mod tests {
         assert_eq!(123, age_group.id);
 
         assert!(age_group.name.is_some());
-        assert!(age_group.name.unwrap().eq("13-14 mixed".into()));
+        assert!(age_group.name.unwrap().eq("13-14 mixed"));
 
         assert!(age_group.age_min.is_some());
         assert_eq!(13, age_group.age_min.unwrap());
@@ -156,6 +273,8 @@ mod tests {
                 gender: Gender::default(),
                 calculate: Calculate::default(),
                 name: Some("age group 1 name".into()),
+                handicap: None,
+                extra: Extra::default(),
             },
             AgeGroup {
                 id: 456,
@@ -164,6 +283,8 @@ mod tests {
                 gender: Gender::default(),
                 calculate: Calculate::default(),
                 name: Some("age group 2 name".into()),
+                handicap: None,
+                extra: Extra::default(),
             },
         ];
 
@@ -176,4 +297,112 @@ mod tests {
             xml
         );
     }
+
+    #[test]
+    fn accepts() {
+        use chrono::NaiveDate;
+
+        use crate::model::{AgeDateType, BirthDate};
+
+        let age_date = AgeDate {
+            value: NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+            r#type: AgeDateType::Year,
+            ..Default::default()
+        };
+        let athlete = |gender, year| {
+            Athlete::new(
+                1,
+                "Jane".into(),
+                "Doe".into(),
+                gender,
+                NaiveDate::from_ymd_opt(year, 6, 1).unwrap().into(),
+            )
+        };
+
+        let mut age_group = AgeGroup::new(1, Some(11), Some(12));
+        age_group.gender = Gender::Female;
+        assert!(age_group.accepts(&athlete(Gender::Female, 2012), &age_date));
+        assert!(!age_group.accepts(&athlete(Gender::Female, 2010), &age_date));
+        assert!(!age_group.accepts(&athlete(Gender::Male, 2012), &age_date));
+
+        let swimmers = [
+            athlete(Gender::Female, 2012),
+            athlete(Gender::Female, 2011),
+            athlete(Gender::Female, 2011),
+            athlete(Gender::Female, 2010),
+        ];
+        let relay: Vec<&Athlete> = swimmers.iter().collect();
+        assert!(!age_group.accepts_relay(&relay, &age_date));
+        assert!(age_group.accepts_relay(&relay[..3], &age_date));
+
+        let total = AgeGroup {
+            calculate: Calculate::Total,
+            ..AgeGroup::new(2, Some(40), Some(49))
+        };
+        assert!(total.accepts(&swimmers[3], &age_date));
+        assert!(total.accepts_relay(&relay, &age_date));
+        assert!(!total.accepts_relay(&relay[..3], &age_date));
+
+        let unknown = fast_xml::de::from_str::<Athlete>(
+            r#"<ATHLETE athleteid="2" firstname="" lastname="" gender="F"/>"#,
+        )
+        .unwrap();
+        assert!(!age_group.accepts(&unknown, &age_date));
+        assert!(AgeGroup::new(3, None, None).accepts(&unknown, &age_date));
+
+        let born_in = Athlete::new(
+            3,
+            "Jane".into(),
+            "Doe".into(),
+            Gender::Female,
+            BirthDate::Year(2012),
+        );
+        assert!(age_group.accepts(&born_in, &age_date));
+
+        let exact_age_date = AgeDate {
+            r#type: AgeDateType::Date,
+            ..age_date
+        };
+        assert!(!age_group.accepts(&born_in, &exact_age_date));
+    }
+
+    #[test]
+    fn total_age_bounds() {
+        use chrono::NaiveDate;
+
+        use crate::model::AgeDateType;
+
+        let result = de::from_str::<AgeGroup>(
+            r#"<AGEGROUP agegroupid="9" agemin="280" agemax="319" calculate="TOTAL"/>"#,
+        );
+        assert!(result.is_ok());
+
+        let age_group = result.unwrap();
+        assert_eq!(Some(280), age_group.age_min);
+        assert_eq!(Some(319), age_group.age_max);
+
+        let age_date = AgeDate {
+            value: NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+            r#type: AgeDateType::Year,
+            ..Default::default()
+        };
+        let swimmers: Vec<Athlete> = [1950, 1948, 1945, 1952, 1990]
+            .into_iter()
+            .map(|year| {
+                Athlete::new(
+                    1,
+                    "Jane".into(),
+                    "Doe".into(),
+                    Gender::Female,
+                    NaiveDate::from_ymd_opt(year, 6, 1).unwrap().into(),
+                )
+            })
+            .collect();
+        let relay: Vec<&Athlete> = swimmers.iter().collect();
+        assert!(age_group.accepts_relay(&relay[..4], &age_date));
+        assert!(!age_group.accepts_relay(&relay[1..], &age_date));
+
+        let result = de::from_str::<AgeGroup>(r#"<AGEGROUP agegroupid="9" agemin="70000"/>"#);
+        assert!(result.is_err());
+    }
 }
@@ -1,11 +1,22 @@
-use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
-use crate::collection::Collection;
+use crate::{
+    collection::Collection,
+    serialization::preserve::{
+        preserve_child, preserve_collection, restore_child, restore_collection, Node, Preserve,
+    },
+};
 
-use super::{entry::Entry, gender::Gender};
+pub use self::status::AthleteStatus;
 
-#[derive(Serialize, Deserialize, PartialEq, Default, Debug)]
+use super::{
+    birth_date::BirthDate, contact::Contact, entry::Entry, extra::Extra, gender::Gender,
+    handicap::Handicap, result::Result,
+};
+
+mod status;
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
 #[serde(rename = "ATHLETE")]
 pub struct Athlete {
     #[serde(rename = "athleteid")]
@@ -17,13 +28,364 @@ pub struct Athlete {
     #[serde(rename = "lastname")]
     last_name: String,
 
+    #[serde(rename = "firstname.en")]
+    first_name_en: Option<String>,
+
+    #[serde(rename = "lastname.en")]
+    last_name_en: Option<String>,
+
+    #[serde(rename = "nameprefix")]
+    name_prefix: Option<String>,
+
     gender: Gender,
 
     license: Option<String>,
 
-    #[serde(rename = "birthdate")]
-    birth_date: NaiveDate,
+    nation: Option<String>,
+
+    level: Option<String>,
+
+    swrid: Option<u32>,
+
+    passport: Option<String>,
+
+    status: Option<AthleteStatus>,
+
+    #[serde(rename = "birthdate", default)]
+    birth_date: Option<BirthDate>,
+
+    #[serde(rename = "CONTACT")]
+    contact: Option<Contact>,
 
-    #[serde(rename = "ENTRIES")]
+    #[serde(rename = "HANDICAP")]
+    handicap: Option<Handicap>,
+
+    #[serde(rename = "ENTRIES", default)]
     entries: Collection<Entry>,
+
+    #[serde(rename = "RESULTS", default, skip_serializing_if = "Vec::is_empty")]
+    results: Collection<Result>,
+
+    #[serde(skip)]
+    extra: Extra,
+}
+
+impl Athlete {
+    pub fn new(
+        id: u32,
+        first_name: String,
+        last_name: String,
+        gender: Gender,
+        birth_date: BirthDate,
+    ) -> Self {
+        Self {
+            id,
+            first_name,
+            last_name,
+            gender,
+            birth_date: Some(birth_date),
+            ..Default::default()
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn first_name(&self) -> &str {
+        &self.first_name
+    }
+
+    pub fn last_name(&self) -> &str {
+        &self.last_name
+    }
+
+    pub fn first_name_en(&self) -> Option<&str> {
+        self.first_name_en.as_deref()
+    }
+
+    pub fn last_name_en(&self) -> Option<&str> {
+        self.last_name_en.as_deref()
+    }
+
+    pub fn name_prefix(&self) -> Option<&str> {
+        self.name_prefix.as_deref()
+    }
+
+    pub fn gender(&self) -> &Gender {
+        &self.gender
+    }
+
+    pub fn license(&self) -> Option<&str> {
+        self.license.as_deref()
+    }
+
+    pub fn nation(&self) -> Option<&str> {
+        self.nation.as_deref()
+    }
+
+    pub fn level(&self) -> Option<&str> {
+        self.level.as_deref()
+    }
+
+    pub fn swrid(&self) -> Option<u32> {
+        self.swrid
+    }
+
+    pub fn passport(&self) -> Option<&str> {
+        self.passport.as_deref()
+    }
+
+    pub fn status(&self) -> Option<&AthleteStatus> {
+        self.status.as_ref()
+    }
+
+    /// The birth date of the athlete, which registration files do not always give, or give as
+    /// a year only.
+    pub fn birth_date(&self) -> Option<BirthDate> {
+        self.birth_date
+    }
+
+    pub fn contact(&self) -> Option<&Contact> {
+        self.contact.as_ref()
+    }
+
+    pub fn handicap(&self) -> Option<&Handicap> {
+        self.handicap.as_ref()
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn entries_mut(&mut self) -> &mut Vec<Entry> {
+        &mut self.entries
+    }
+
+    pub fn results(&self) -> &[Result] {
+        &self.results
+    }
+
+    pub fn results_mut(&mut self) -> &mut Vec<Result> {
+        &mut self.results
+    }
+
+    pub(crate) fn with_id(&mut self, id: u32) -> &mut Self {
+        self.id = id;
+
+        self
+    }
+
+    pub fn with_license(&mut self, license: String) -> &mut Self {
+        self.license = Some(license);
+
+        self
+    }
+
+    pub fn with_first_name_en(&mut self, first_name: String) -> &mut Self {
+        self.first_name_en = Some(first_name);
+
+        self
+    }
+
+    pub fn with_last_name_en(&mut self, last_name: String) -> &mut Self {
+        self.last_name_en = Some(last_name);
+
+        self
+    }
+
+    pub fn with_name_prefix(&mut self, name_prefix: String) -> &mut Self {
+        self.name_prefix = Some(name_prefix);
+
+        self
+    }
+
+    pub fn with_nation(&mut self, nation: String) -> &mut Self {
+        self.nation = Some(nation);
+
+        self
+    }
+
+    pub fn with_level(&mut self, level: String) -> &mut Self {
+        self.level = Some(level);
+
+        self
+    }
+
+    pub fn with_swrid(&mut self, swrid: u32) -> &mut Self {
+        self.swrid = Some(swrid);
+
+        self
+    }
+
+    pub fn with_passport(&mut self, passport: String) -> &mut Self {
+        self.passport = Some(passport);
+
+        self
+    }
+
+    pub fn with_status(&mut self, status: AthleteStatus) -> &mut Self {
+        self.status = Some(status);
+
+        self
+    }
+
+    pub fn with_contact(&mut self, contact: Contact) -> &mut Self {
+        self.contact = Some(contact);
+
+        self
+    }
+
+    pub fn with_handicap(&mut self, handicap: Handicap) -> &mut Self {
+        self.handicap = Some(handicap);
+
+        self
+    }
+
+    pub fn add_entry(&mut self, entry: Entry) -> &mut Self {
+        self.entries.push(entry);
+
+        self
+    }
+
+    pub fn add_result(&mut self, result: Result) -> &mut Self {
+        self.results.push(result);
+
+        self
+    }
+
+    /// The attributes and child elements of this element unknown to the model.
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
+}
+
+impl Preserve for Athlete {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_child(self.contact.as_mut(), node, "CONTACT");
+        preserve_child(self.handicap.as_mut(), node, "HANDICAP");
+        preserve_collection(&mut self.entries, node, "ENTRIES");
+        preserve_collection(&mut self.results, node, "RESULTS");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_child(self.contact.as_ref(), node, "CONTACT");
+        restore_child(self.handicap.as_ref(), node, "HANDICAP");
+        restore_collection(&self.entries, node, "ENTRIES");
+        restore_collection(&self.results, node, "RESULTS");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use fast_xml::{de, se};
+
+    use super::*;
+    use crate::model::SportClass;
+
+    #[test]
+    fn deserialize_missing_id() {
+        let result = de::from_str::<Athlete>(
+            r#"<ATHLETE firstname="Jane" lastname="Doe" gender="F" birthdate="2010-04-30"/>"#,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deserialize_basic() {
+        let result = de::from_str::<Athlete>(
+            r#"<ATHLETE athleteid="7" firstname="Jane" lastname="Doe" gender="F" license="QC123" birthdate="2010-04-30"/>"#,
+        );
+        assert!(result.is_ok());
+
+        let athlete = result.unwrap();
+        assert_eq!(7, athlete.id());
+        assert_eq!("Jane", athlete.first_name());
+        assert_eq!("Doe", athlete.last_name());
+        assert_eq!(&Gender::Female, athlete.gender());
+        assert_eq!(Some("QC123"), athlete.license());
+        assert_eq!(Some(2010), athlete.birth_date().map(|date| date.year()));
+        assert!(athlete.entries().is_empty());
+    }
+
+    #[test]
+    fn year_of_birth() {
+        let xml = r#"<ATHLETE athleteid="7" firstname="Jane" lastname="Doe" gender="F" birthdate="2010"/>"#;
+        let result = de::from_str::<Athlete>(xml);
+        assert!(result.is_ok());
+
+        let athlete = result.unwrap();
+        assert_eq!(Some(BirthDate::Year(2010)), athlete.birth_date());
+        assert_eq!(
+            athlete,
+            Athlete::new(
+                7,
+                "Jane".into(),
+                "Doe".into(),
+                Gender::Female,
+                BirthDate::Year(2010)
+            )
+        );
+        assert_eq!(
+            xml.replace("/>", "><ENTRIES/></ATHLETE>"),
+            se::to_string(&athlete).unwrap()
+        );
+    }
+
+    #[test]
+    fn deserialize_full() {
+        let result = de::from_str::<Athlete>(
+            r#"<ATHLETE athleteid="8" firstname="Zoé" firstname.en="Zoe" lastname="Côté" lastname.en="Cote" nameprefix="de" gender="F" nation="CAN" level="AA" swrid="4012345" passport="P123" status="FOREIGNER"><HANDICAP free="9" breast="8" medley="9"/></ATHLETE>"#,
+        );
+        assert!(result.is_ok());
+
+        let athlete = result.unwrap();
+        assert_eq!(Some("Zoe"), athlete.first_name_en());
+        assert_eq!(Some("Cote"), athlete.last_name_en());
+        assert_eq!(Some("de"), athlete.name_prefix());
+        assert_eq!(Some("CAN"), athlete.nation());
+        assert_eq!(Some("AA"), athlete.level());
+        assert_eq!(Some(4012345), athlete.swrid());
+        assert_eq!(Some("P123"), athlete.passport());
+        assert_eq!(Some(&AthleteStatus::Foreigner), athlete.status());
+        assert_eq!(
+            Some(SportClass::Class(8)),
+            athlete.handicap().unwrap().breast
+        );
+        assert_eq!(None, athlete.birth_date());
+    }
+
+    #[test]
+    fn serialize_built() {
+        let mut athlete = Athlete::new(
+            7,
+            "Jane".into(),
+            "Doe".into(),
+            Gender::Female,
+            NaiveDate::from_ymd_opt(2010, 4, 30).unwrap().into(),
+        );
+        athlete
+            .with_license("QC123".into())
+            .with_contact(Contact {
+                email: Some("jane@example.com".into()),
+                ..Default::default()
+            })
+            .add_entry(Entry::new(3));
+
+        let result = se::to_string(&athlete);
+        assert!(result.is_ok());
+        assert_eq!(
+            r#"<ATHLETE athleteid="7" firstname="Jane" lastname="Doe" gender="F" license="QC123" birthdate="2010-04-30"><CONTACT email="jane@example.com"/><ENTRIES><ENTRY eventid="3"/></ENTRIES></ATHLETE>"#,
+            result.unwrap()
+        );
+    }
 }
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
+
+#[derive(Serialize, Deserialize, IntoStaticStr, PartialEq, Debug, Clone)]
+#[serde(rename_all = "UPPERCASE", into = "&str")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum AthleteStatus {
+    /// Swims outside of the competition and is not ranked.
+    Exhibition,
+
+    /// Only relevant for national championships.
+    Foreigner,
+
+    Rookie,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fast_xml::{de, se};
+
+    #[test]
+    fn serialize() {
+        let value = AthleteStatus::Exhibition;
+        let result = se::to_string(&value);
+        assert!(result.is_ok());
+
+        assert_eq!("EXHIBITION", result.unwrap());
+    }
+
+    #[test]
+    fn deserialize() {
+        let result = de::from_str::<AthleteStatus>("FOREIGNER");
+        assert!(result.is_ok());
+
+        assert_eq!(AthleteStatus::Foreigner, result.unwrap());
+    }
+}
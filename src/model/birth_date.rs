@@ -0,0 +1,121 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+/// The birth date of an athlete, written `YYYY-MM-DD` in documents, or `YYYY` when only the
+/// year of birth is known.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum BirthDate {
+    Date(NaiveDate),
+
+    /// Only the year of birth.
+    Year(i32),
+}
+
+#[derive(Error, PartialEq, Debug, Clone)]
+#[error("Invalid birth date {0}, expected YYYY-MM-DD or YYYY.")]
+pub struct ParseBirthDateError(pub String);
+
+impl BirthDate {
+    pub fn year(&self) -> i32 {
+        match self {
+            BirthDate::Date(date) => date.year(),
+            BirthDate::Year(year) => *year,
+        }
+    }
+
+    /// The full birth date, `None` when only the year is known.
+    pub fn date(&self) -> Option<NaiveDate> {
+        match self {
+            BirthDate::Date(date) => Some(*date),
+            BirthDate::Year(_) => None,
+        }
+    }
+}
+
+impl From<NaiveDate> for BirthDate {
+    fn from(date: NaiveDate) -> Self {
+        BirthDate::Date(date)
+    }
+}
+
+impl Display for BirthDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BirthDate::Date(date) => write!(f, "{}", date.format("%Y-%m-%d")),
+            BirthDate::Year(year) => write!(f, "{year:04}"),
+        }
+    }
+}
+
+impl FromStr for BirthDate {
+    type Err = ParseBirthDateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseBirthDateError(s.to_string());
+
+        if s.len() == 4 && s.chars().all(|c| c.is_ascii_digit()) {
+            return s.parse().map(BirthDate::Year).map_err(|_| error());
+        }
+
+        NaiveDate::parse_from_str(s, "%Y-%m-%d")
+            .map(BirthDate::Date)
+            .map_err(|_| error())
+    }
+}
+
+impl Serialize for BirthDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for BirthDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Ok(BirthDate::Date(
+                NaiveDate::from_ymd_opt(2010, 4, 30).unwrap()
+            )),
+            "2010-04-30".parse()
+        );
+        assert_eq!(Ok(BirthDate::Year(2010)), "2010".parse());
+
+        assert!("".parse::<BirthDate>().is_err());
+        assert!("10".parse::<BirthDate>().is_err());
+        assert!("+201".parse::<BirthDate>().is_err());
+        assert!("2010-02-30".parse::<BirthDate>().is_err());
+    }
+
+    #[test]
+    fn display() {
+        let date = BirthDate::from(NaiveDate::from_ymd_opt(2010, 4, 30).unwrap());
+
+        assert_eq!("2010-04-30", date.to_string());
+        assert_eq!("2010", BirthDate::Year(2010).to_string());
+        assert_eq!(2010, date.year());
+        assert_eq!(None, BirthDate::Year(2010).date());
+    }
+}
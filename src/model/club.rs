@@ -1,23 +1,324 @@
 use serde::{Deserialize, Serialize};
 
-use crate::collection::Collection;
+use crate::{
+    collection::Collection,
+    serialization::preserve::{
+        preserve_child, preserve_collection, restore_child, restore_collection, Node, Preserve,
+    },
+};
 
-use super::athlete::Athlete;
+pub use self::club_type::ClubType;
 
-#[derive(Serialize, Deserialize, PartialEq, Default, Debug)]
+use super::{
+    athlete::Athlete, contact::Contact, extra::Extra, relay::Relay, relay_position::RelayPosition,
+};
+
+mod club_type;
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
 #[serde(rename = "CLUB")]
 pub struct Club {
     #[serde(rename = "clubid")]
     id: u32,
 
+    /// Left empty by some exports for unattached athletes.
+    #[serde(default)]
     name: String,
 
+    #[serde(rename = "name.en")]
+    name_en: Option<String>,
+
+    #[serde(rename = "shortname")]
+    short_name: Option<String>,
+
     code: Option<String>,
 
     nation: Option<String>,
 
     region: Option<String>,
 
+    swrid: Option<u32>,
+
+    r#type: Option<ClubType>,
+
+    #[serde(rename = "CONTACT")]
+    contact: Option<Contact>,
+
     #[serde(rename = "ATHLETES")]
     athletes: Collection<Athlete>,
+
+    #[serde(rename = "RELAYS", default, skip_serializing_if = "Vec::is_empty")]
+    relays: Collection<Relay>,
+
+    #[serde(skip)]
+    extra: Extra,
+}
+
+impl Club {
+    pub fn new(id: u32, name: String) -> Self {
+        Self {
+            id,
+            name,
+            ..Default::default()
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn name_en(&self) -> Option<&str> {
+        self.name_en.as_deref()
+    }
+
+    pub fn short_name(&self) -> Option<&str> {
+        self.short_name.as_deref()
+    }
+
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    pub fn nation(&self) -> Option<&str> {
+        self.nation.as_deref()
+    }
+
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    pub fn swrid(&self) -> Option<u32> {
+        self.swrid
+    }
+
+    pub fn r#type(&self) -> Option<&ClubType> {
+        self.r#type.as_ref()
+    }
+
+    /// Whether this club groups athletes swimming for no club.
+    pub fn is_unattached(&self) -> bool {
+        self.r#type == Some(ClubType::Unattached)
+    }
+
+    pub fn contact(&self) -> Option<&Contact> {
+        self.contact.as_ref()
+    }
+
+    pub fn athletes(&self) -> &[Athlete] {
+        &self.athletes
+    }
+
+    pub fn athletes_mut(&mut self) -> &mut Vec<Athlete> {
+        &mut self.athletes
+    }
+
+    pub fn relays(&self) -> &[Relay] {
+        &self.relays
+    }
+
+    pub fn relays_mut(&mut self) -> &mut Vec<Relay> {
+        &mut self.relays
+    }
+
+    pub(crate) fn with_id(&mut self, id: u32) -> &mut Self {
+        self.id = id;
+
+        self
+    }
+
+    pub fn with_name_en(&mut self, name: String) -> &mut Self {
+        self.name_en = Some(name);
+
+        self
+    }
+
+    pub fn with_short_name(&mut self, short_name: String) -> &mut Self {
+        self.short_name = Some(short_name);
+
+        self
+    }
+
+    pub fn with_code(&mut self, code: String) -> &mut Self {
+        self.code = Some(code);
+
+        self
+    }
+
+    pub fn with_nation(&mut self, nation: String) -> &mut Self {
+        self.nation = Some(nation);
+
+        self
+    }
+
+    pub fn with_region(&mut self, region: String) -> &mut Self {
+        self.region = Some(region);
+
+        self
+    }
+
+    pub fn with_swrid(&mut self, swrid: u32) -> &mut Self {
+        self.swrid = Some(swrid);
+
+        self
+    }
+
+    pub fn with_type(&mut self, r#type: ClubType) -> &mut Self {
+        self.r#type = Some(r#type);
+
+        self
+    }
+
+    pub fn with_contact(&mut self, contact: Contact) -> &mut Self {
+        self.contact = Some(contact);
+
+        self
+    }
+
+    pub fn add_athlete(&mut self, athlete: Athlete) -> &mut Self {
+        self.athletes.push(athlete);
+
+        self
+    }
+
+    pub fn add_relay(&mut self, relay: Relay) -> &mut Self {
+        self.relays.push(relay);
+
+        self
+    }
+
+    pub fn athlete(&self, id: u32) -> Option<&Athlete> {
+        self.athletes.iter().find(|athlete| athlete.id() == id)
+    }
+
+    /// Resolves each relay position to the athlete of this club swimming it.
+    ///
+    /// Positions are returned in the order of their `number`. A position whose athlete is not
+    /// listed in this club resolves to `None`.
+    pub fn relay_athletes<'a>(
+        &'a self,
+        positions: &'a [RelayPosition],
+    ) -> Vec<(&'a RelayPosition, Option<&'a Athlete>)> {
+        let mut athletes: Vec<_> = positions
+            .iter()
+            .map(|position| {
+                (
+                    position,
+                    position.athlete_id.and_then(|id| self.athlete(id)),
+                )
+            })
+            .collect();
+        athletes.sort_by_key(|(position, _)| position.number);
+
+        athletes
+    }
+
+    /// The attributes and child elements of this element unknown to the model.
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
+}
+
+impl Preserve for Club {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_child(self.contact.as_mut(), node, "CONTACT");
+        preserve_collection(&mut self.athletes, node, "ATHLETES");
+        preserve_collection(&mut self.relays, node, "RELAYS");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_child(self.contact.as_ref(), node, "CONTACT");
+        restore_collection(&self.athletes, node, "ATHLETES");
+        restore_collection(&self.relays, node, "RELAYS");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fast_xml::{de, se};
+
+    use super::*;
+
+    #[test]
+    fn deserialize_relays() {
+        let result = de::from_str::<Club>(
+            r#"<CLUB clubid="1" name="Club"><ATHLETES/><RELAYS><RELAY number="1" gender="M"/><RELAY number="2" gender="M"/></RELAYS></CLUB>"#,
+        );
+        assert!(result.is_ok());
+
+        let club = result.unwrap();
+        assert_eq!(2, club.relays().len());
+    }
+
+    #[test]
+    fn deserialize_contact() {
+        let result = de::from_str::<Club>(
+            r#"<CLUB clubid="1" name="Club"><CONTACT name="Coach" phone="514 555-0102" city="Laval"/><ATHLETES/></CLUB>"#,
+        );
+        assert!(result.is_ok());
+
+        let club = result.unwrap();
+        let contact = club.contact().unwrap();
+        assert_eq!(Some("Coach"), contact.name.as_deref());
+        assert_eq!(Some("514 555-0102"), contact.phone.as_deref());
+        assert_eq!(Some("Laval"), contact.city.as_deref());
+    }
+
+    #[test]
+    fn deserialize_unattached() {
+        let result = de::from_str::<Club>(
+            r#"<CLUB clubid="9" type="UNATTACHED" nation="CAN"><ATHLETES><ATHLETE athleteid="1" firstname="A" lastname="A" gender="F"/></ATHLETES></CLUB>"#,
+        );
+        assert!(result.is_ok());
+
+        let club = result.unwrap();
+        assert!(club.is_unattached());
+        assert_eq!("", club.name());
+        assert_eq!(1, club.athletes().len());
+    }
+
+    #[test]
+    fn serialize_national_team() {
+        let mut club = Club::new(5, "Équipe du Canada".into());
+        club.with_name_en("Team Canada".into())
+            .with_short_name("CAN".into())
+            .with_swrid(123)
+            .with_type(ClubType::NationalTeam);
+
+        let result = se::to_string(&club);
+        assert!(result.is_ok());
+        assert_eq!(
+            r#"<CLUB clubid="5" name="Équipe du Canada" name.en="Team Canada" shortname="CAN" swrid="123" type="NATIONALTEAM"><ATHLETES/></CLUB>"#,
+            result.unwrap()
+        );
+    }
+
+    #[test]
+    fn resolve_relay_athletes() {
+        let result = de::from_str::<Club>(
+            r#"<CLUB clubid="1" name="Club"><ATHLETES><ATHLETE athleteid="10" firstname="A" lastname="A" gender="F" birthdate="2010-01-01"/><ATHLETE athleteid="11" firstname="B" lastname="B" gender="F" birthdate="2010-01-01"/></ATHLETES><RELAYS><RELAY number="1" gender="F"><ENTRIES><ENTRY eventid="4"><RELAYPOSITIONS><RELAYPOSITION athleteid="11" number="2"/><RELAYPOSITION athleteid="10" number="1"/><RELAYPOSITION athleteid="99" number="3"/></RELAYPOSITIONS></ENTRY></ENTRIES></RELAY></RELAYS></CLUB>"#,
+        );
+        assert!(result.is_ok());
+
+        let club = result.unwrap();
+        let entry = club.relays().first().unwrap().entries().first().unwrap();
+        let athletes = club.relay_athletes(entry.relay_positions());
+
+        assert_eq!(3, athletes.len());
+        assert_eq!(1, athletes[0].0.number);
+        assert_eq!(10, athletes[0].1.unwrap().id());
+        assert_eq!(11, athletes[1].1.unwrap().id());
+        assert!(athletes[2].1.is_none());
+    }
 }
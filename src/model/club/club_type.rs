@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
+
+#[derive(Serialize, Deserialize, IntoStaticStr, PartialEq, Debug, Clone)]
+#[serde(rename_all = "UPPERCASE", into = "&str")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum ClubType {
+    Club,
+
+    NationalTeam,
+
+    RegionalTeam,
+
+    /// Groups the athletes swimming for no club.
+    Unattached,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fast_xml::{de, se};
+
+    #[test]
+    fn serialize() {
+        let value = ClubType::NationalTeam;
+        let result = se::to_string(&value);
+        assert!(result.is_ok());
+
+        assert_eq!("NATIONALTEAM", result.unwrap());
+    }
+
+    #[test]
+    fn deserialize() {
+        let result = de::from_str::<ClubType>("UNATTACHED");
+        assert!(result.is_ok());
+
+        assert_eq!(ClubType::Unattached, result.unwrap());
+    }
+}
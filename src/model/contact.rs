@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+use crate::serialization::preserve::Preserve;
+
+use super::extra::Extra;
+
+/// The address of a person or an organization, such as a meet organizer, a club or an official.
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[serde(rename = "CONTACT")]
+pub struct Contact {
+    pub name: Option<String>,
+
+    pub street: Option<String>,
+
+    pub street2: Option<String>,
+
+    pub zip: Option<String>,
+
+    pub city: Option<String>,
+
+    pub state: Option<String>,
+
+    pub country: Option<String>,
+
+    pub phone: Option<String>,
+
+    pub mobile: Option<String>,
+
+    pub fax: Option<String>,
+
+    pub email: Option<String>,
+
+    pub internet: Option<String>,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+impl Preserve for Contact {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fast_xml::{de, se};
+
+    use super::*;
+
+    #[test]
+    fn deserialize_empty() {
+        let result = de::from_str::<Contact>(r#"<CONTACT/>"#);
+        assert!(result.is_ok());
+        assert_eq!(Contact::default(), result.unwrap());
+    }
+
+    #[test]
+    fn deserialize_full() {
+        let result = de::from_str::<Contact>(
+            r#"<CONTACT city="Montréal" country="CA" email="info@example.com" fax="514 555-0100" internet="https://example.com" mobile="514 555-0101" name="Club" phone="514 555-0102" state="QC" street="4141 Pierre-De Coubertin" street2="Bureau 1" zip="H1V 3N7"/>"#,
+        );
+        assert!(result.is_ok());
+
+        let contact = result.unwrap();
+        assert_eq!(Some("Montréal"), contact.city.as_deref());
+        assert_eq!(Some("514 555-0100"), contact.fax.as_deref());
+        assert_eq!(Some("514 555-0101"), contact.mobile.as_deref());
+        assert_eq!(Some("QC"), contact.state.as_deref());
+        assert_eq!(Some("Bureau 1"), contact.street2.as_deref());
+        assert_eq!(Some("H1V 3N7"), contact.zip.as_deref());
+    }
+
+    #[test]
+    fn serialize_partial() {
+        let contact = Contact {
+            name: Some("Jane Doe".into()),
+            email: Some("jane@example.com".into()),
+            ..Default::default()
+        };
+
+        let result = se::to_string(&contact);
+        assert!(result.is_ok());
+        assert_eq!(
+            r#"<CONTACT name="Jane Doe" email="jane@example.com"/>"#,
+            result.unwrap()
+        );
+    }
+}
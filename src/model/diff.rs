@@ -0,0 +1,604 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Display, Formatter},
+};
+
+use chrono::NaiveTime;
+
+use crate::serialization::serde_time;
+
+use super::{
+    age_group::AgeGroup, event::Event, fee::Fee, lenex::Lenex, meet::Meet, session::Session,
+    swimstyle::SwimStyle,
+};
+
+/// The element a [`Change`] applies to, identified by the ids stable across reissues of a
+/// document.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub enum Key {
+    /// The meet at this position of the document.
+    Meet(usize),
+    Session(u32),
+    Event(u32),
+    AgeGroup {
+        event_id: u32,
+        id: u32,
+    },
+    /// The meet fee of this type, `index` telling apart the fees of the same type by their
+    /// position among them.
+    Fee {
+        r#type: String,
+        index: usize,
+    },
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum Change {
+    Added(Key),
+    Removed(Key),
+    /// An attribute or child of the element changed. Values are rendered as in the document,
+    /// `None` meaning the value is missing.
+    Modified {
+        key: Key,
+        field: &'static str,
+        old: Option<String>,
+        new: Option<String>,
+    },
+}
+
+/// The changes between two versions of a document, as returned by [`diff`].
+///
+/// It renders one change per line.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Diff {
+    pub changes: Vec<Change>,
+}
+
+impl Diff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Key::Meet(index) => write!(f, "meet {}", index + 1),
+            Key::Session(number) => write!(f, "session {number}"),
+            Key::Event(id) => write!(f, "event {id}"),
+            Key::AgeGroup { event_id, id } => write!(f, "age group {id} of event {event_id}"),
+            Key::Fee { r#type, index } => {
+                f.write_str("fee")?;
+                if !r#type.is_empty() {
+                    write!(f, " {type}")?;
+                }
+                if *index > 0 {
+                    write!(f, " #{}", index + 1)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Added(key) => write!(f, "{key} added"),
+            Change::Removed(key) => write!(f, "{key} removed"),
+            Change::Modified {
+                key,
+                field,
+                old,
+                new,
+            } => match (old, new) {
+                (Some(old), Some(new)) => write!(f, "{key}: {field} changed from {old} to {new}"),
+                (None, Some(new)) => write!(f, "{key}: {field} set to {new}"),
+                (Some(old), None) => write!(f, "{key}: {field} {old} removed"),
+                (None, None) => write!(f, "{key}: {field} changed"),
+            },
+        }
+    }
+}
+
+impl Display for Diff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for change in self.changes.iter() {
+            writeln!(f, "{change}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Compares two versions of a document, typically an invitation and its reissue.
+///
+/// Meets are compared by position, sessions by number, events by id, age groups by id within
+/// their event and meet fees by type, then by position among the fees of the same type. Fee
+/// values are rendered with their currency.
+pub fn diff(old: &Lenex, new: &Lenex) -> Diff {
+    let mut changes = Vec::new();
+
+    for index in 0..old.meets.len().max(new.meets.len()) {
+        match (old.meets.get(index), new.meets.get(index)) {
+            (Some(old), Some(new)) => diff_meet(&mut changes, index, old, new),
+            (Some(_), None) => changes.push(Change::Removed(Key::Meet(index))),
+            (None, Some(_)) => changes.push(Change::Added(Key::Meet(index))),
+            (None, None) => {}
+        }
+    }
+
+    Diff { changes }
+}
+
+fn text<T: ToString>(value: Option<T>) -> Option<String> {
+    value.map(|value| value.to_string())
+}
+
+/// A time of day, rendered as in the document.
+fn time(value: Option<NaiveTime>) -> Option<String> {
+    value.map(|value| value.format(serde_time::TIME_FORMAT).to_string())
+}
+
+fn label<'a, T>(value: Option<&'a T>) -> Option<String>
+where
+    &'a T: Into<&'static str>,
+{
+    value.map(|value| value.into().to_string())
+}
+
+/// The value of the fee followed by its currency, when it has one.
+fn amount(fee: Option<&Fee>) -> Option<String> {
+    fee.map(|fee| match fee.currency() {
+        Some(currency) => format!("{} {currency}", fee.value()),
+        None => fee.value().to_string(),
+    })
+}
+
+fn swim_style(swim_style: &SwimStyle) -> String {
+    let stroke: &str = (&swim_style.stroke).into();
+
+    match swim_style.relay_count {
+        0 | 1 => format!("{} {stroke}", swim_style.distance),
+        count => format!("{count}x{} {stroke}", swim_style.distance),
+    }
+}
+
+fn modified(
+    changes: &mut Vec<Change>,
+    key: &Key,
+    field: &'static str,
+    old: Option<String>,
+    new: Option<String>,
+) {
+    if old != new {
+        changes.push(Change::Modified {
+            key: key.clone(),
+            field,
+            old,
+            new,
+        });
+    }
+}
+
+/// Pairs the elements of both versions by key, in key order.
+fn pair<'a, K: Ord, T>(
+    old: impl IntoIterator<Item = (K, &'a T)>,
+    new: impl IntoIterator<Item = (K, &'a T)>,
+) -> BTreeMap<K, (Option<&'a T>, Option<&'a T>)> {
+    let mut pairs: BTreeMap<K, (Option<&T>, Option<&T>)> = BTreeMap::new();
+    for (key, item) in old {
+        pairs.entry(key).or_default().0.get_or_insert(item);
+    }
+    for (key, item) in new {
+        pairs.entry(key).or_default().1.get_or_insert(item);
+    }
+
+    pairs
+}
+
+/// The fees of the meet by type and position among the fees of that type.
+fn fees(meet: &Meet) -> Vec<((String, usize), &Fee)> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+
+    meet.fees()
+        .iter()
+        .map(|fee| {
+            let r#type = fee.r#type().map(ToString::to_string).unwrap_or_default();
+            let count = counts.entry(r#type.clone()).or_default();
+            let index = *count;
+            *count += 1;
+
+            ((r#type, index), fee)
+        })
+        .collect()
+}
+
+fn sessions(meet: &Meet) -> Vec<(u32, &Session)> {
+    meet.sessions()
+        .iter()
+        .map(|session| (session.number(), session))
+        .collect()
+}
+
+/// The events of the meet by id, with the number of their session.
+fn events(meet: &Meet) -> BTreeMap<u32, (u32, &Event)> {
+    meet.sessions()
+        .iter()
+        .flat_map(|session| {
+            session
+                .events()
+                .iter()
+                .map(move |event| (event.id, (session.number(), event)))
+        })
+        .collect()
+}
+
+fn age_groups(event: &Event) -> Vec<(u32, &AgeGroup)> {
+    event
+        .age_groups()
+        .iter()
+        .map(|age_group| (age_group.id, age_group))
+        .collect()
+}
+
+fn diff_meet(changes: &mut Vec<Change>, index: usize, old: &Meet, new: &Meet) {
+    let key = Key::Meet(index);
+    modified(
+        changes,
+        &key,
+        "name",
+        text(Some(old.name())),
+        text(Some(new.name())),
+    );
+    modified(
+        changes,
+        &key,
+        "city",
+        text(Some(old.city())),
+        text(Some(new.city())),
+    );
+    modified(
+        changes,
+        &key,
+        "nation",
+        text(Some(old.nation())),
+        text(Some(new.nation())),
+    );
+    modified(
+        changes,
+        &key,
+        "course",
+        label(old.course()),
+        label(new.course()),
+    );
+    modified(
+        changes,
+        &key,
+        "AGEDATE value",
+        text(old.age_date().map(|age_date| age_date.value)),
+        text(new.age_date().map(|age_date| age_date.value)),
+    );
+    modified(
+        changes,
+        &key,
+        "AGEDATE type",
+        label(old.age_date().map(|age_date| &age_date.r#type)),
+        label(new.age_date().map(|age_date| &age_date.r#type)),
+    );
+
+    for ((r#type, index), fees) in pair(fees(old), fees(new)) {
+        let key = Key::Fee { r#type, index };
+        match fees {
+            (Some(old), Some(new)) => {
+                modified(changes, &key, "value", amount(Some(old)), amount(Some(new)))
+            }
+            (Some(_), None) => changes.push(Change::Removed(key)),
+            (None, Some(_)) => changes.push(Change::Added(key)),
+            (None, None) => {}
+        }
+    }
+
+    for (number, sessions) in pair(sessions(old), sessions(new)) {
+        let key = Key::Session(number);
+        match sessions {
+            (Some(old), Some(new)) => diff_session(changes, &key, old, new),
+            (Some(_), None) => changes.push(Change::Removed(key)),
+            (None, Some(_)) => changes.push(Change::Added(key)),
+            (None, None) => {}
+        }
+    }
+
+    let (old_events, new_events) = (events(old), events(new));
+    let ids: BTreeSet<u32> = old_events
+        .keys()
+        .chain(new_events.keys())
+        .copied()
+        .collect();
+    for id in ids {
+        let key = Key::Event(id);
+        match (old_events.get(&id), new_events.get(&id)) {
+            (Some((old_session, old)), Some((new_session, new))) => {
+                modified(
+                    changes,
+                    &key,
+                    "SESSION",
+                    text(Some(old_session)),
+                    text(Some(new_session)),
+                );
+                diff_event(changes, &key, old, new);
+            }
+            (Some(_), None) => changes.push(Change::Removed(key)),
+            (None, Some(_)) => changes.push(Change::Added(key)),
+            (None, None) => {}
+        }
+    }
+}
+
+fn diff_session(changes: &mut Vec<Change>, key: &Key, old: &Session, new: &Session) {
+    modified(
+        changes,
+        key,
+        "date",
+        text(Some(old.date())),
+        text(Some(new.date())),
+    );
+    modified(
+        changes,
+        key,
+        "daytime",
+        time(old.day_time()),
+        time(new.day_time()),
+    );
+    modified(
+        changes,
+        key,
+        "endtime",
+        time(old.end_time()),
+        time(new.end_time()),
+    );
+    modified(changes, key, "name", text(old.name()), text(new.name()));
+    modified(
+        changes,
+        key,
+        "warmupfrom",
+        time(old.warmup_from()),
+        time(new.warmup_from()),
+    );
+    modified(
+        changes,
+        key,
+        "warmupuntil",
+        time(old.warmup_until()),
+        time(new.warmup_until()),
+    );
+    modified(
+        changes,
+        key,
+        "teamleadermeeting",
+        time(old.team_leader_meeting()),
+        time(new.team_leader_meeting()),
+    );
+}
+
+fn diff_event(changes: &mut Vec<Change>, key: &Key, old: &Event, new: &Event) {
+    modified(
+        changes,
+        key,
+        "number",
+        text(Some(old.number())),
+        text(Some(new.number())),
+    );
+    modified(changes, key, "order", text(old.order), text(new.order));
+    modified(
+        changes,
+        key,
+        "daytime",
+        time(old.day_time()),
+        time(new.day_time()),
+    );
+    modified(
+        changes,
+        key,
+        "gender",
+        label(old.gender()),
+        label(new.gender()),
+    );
+    modified(
+        changes,
+        key,
+        "round",
+        label(old.round()),
+        label(new.round()),
+    );
+    modified(
+        changes,
+        key,
+        "preveventid",
+        text(old.prev_event_id),
+        text(new.prev_event_id),
+    );
+    modified(
+        changes,
+        key,
+        "SWIMSTYLE",
+        Some(swim_style(old.swim_style())),
+        Some(swim_style(new.swim_style())),
+    );
+    modified(changes, key, "FEE", amount(old.fee()), amount(new.fee()));
+
+    for (id, age_groups) in pair(age_groups(old), age_groups(new)) {
+        let key = Key::AgeGroup {
+            event_id: new.id,
+            id,
+        };
+        match age_groups {
+            (Some(old), Some(new)) => diff_age_group(changes, &key, old, new),
+            (Some(_), None) => changes.push(Change::Removed(key)),
+            (None, Some(_)) => changes.push(Change::Added(key)),
+            (None, None) => {}
+        }
+    }
+}
+
+fn diff_age_group(changes: &mut Vec<Change>, key: &Key, old: &AgeGroup, new: &AgeGroup) {
+    modified(changes, key, "agemin", text(old.age_min), text(new.age_min));
+    modified(changes, key, "agemax", text(old.age_max), text(new.age_max));
+    modified(
+        changes,
+        key,
+        "gender",
+        label(Some(&old.gender)),
+        label(Some(&new.gender)),
+    );
+    modified(
+        changes,
+        key,
+        "calculate",
+        label(Some(&old.calculate)),
+        label(Some(&new.calculate)),
+    );
+    modified(
+        changes,
+        key,
+        "name",
+        text(old.name.as_ref()),
+        text(new.name.as_ref()),
+    );
+    modified(
+        changes,
+        key,
+        "handicap",
+        text(old.handicap.as_ref()),
+        text(new.handicap.as_ref()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveTime};
+
+    use super::*;
+    use crate::model::{AgeDate, AgeDateType, FeeType, SportClasses, Stroke, SwimStyle};
+
+    fn invitation() -> Lenex {
+        let mut event = Event::new(10, 1, SwimStyle::new(1, 50, 1, Stroke::Free));
+        event.add_age_group(AgeGroup::new(1, Some(11), Some(12)));
+
+        let mut session = Session::new(1, NaiveDate::from_ymd_opt(2023, 2, 11).unwrap(), vec![]);
+        session
+            .with_day_time(NaiveTime::from_hms_opt(9, 0, 0).unwrap())
+            .add_event(event)
+            .add_event(Event::new(11, 2, SwimStyle::new(2, 100, 1, Stroke::Back)));
+
+        let mut meet = Meet::new(
+            "Meet".into(),
+            "CAN".into(),
+            "Montreal".into(),
+            vec![session],
+        );
+        meet.add_fee(Fee::new(FeeType::Athlete, 1000));
+
+        let mut lenex = Lenex::new();
+        lenex.meets.push(meet);
+
+        lenex
+    }
+
+    #[test]
+    fn diff_same() {
+        let lenex = invitation();
+        assert!(diff(&lenex, &lenex.clone()).is_empty());
+    }
+
+    #[test]
+    fn diff_reissue() {
+        let old = invitation();
+        let mut new = old.clone();
+
+        let meet = &mut new.meets[0];
+        meet.fees_mut()[0] = Fee::new(FeeType::Athlete, 1200);
+
+        let session = &mut meet.sessions_mut()[0];
+        session.with_day_time(NaiveTime::from_hms_opt(9, 30, 0).unwrap());
+
+        let events = session.events_mut();
+        events[0].age_groups_mut()[0].age_max = Some(13);
+        events[0].add_age_group(AgeGroup::new(2, Some(14), None));
+        events.remove(1);
+        events.push(Event::new(12, 2, SwimStyle::new(2, 100, 1, Stroke::Back)));
+        let mut fee = Fee::untyped(800);
+        fee.with_currency("CAD".into());
+        events[0].with_fee(fee);
+
+        let diff = diff(&old, &new);
+        assert_eq!(
+            Change::Modified {
+                key: Key::Fee {
+                    r#type: "ATHLETE".into(),
+                    index: 0
+                },
+                field: "value",
+                old: Some("1000".into()),
+                new: Some("1200".into()),
+            },
+            diff.changes[0]
+        );
+        assert_eq!(
+            "fee ATHLETE: value changed from 1000 to 1200\n\
+             session 1: daytime changed from 09:00 to 09:30\n\
+             event 10: FEE set to 800 CAD\n\
+             age group 1 of event 10: agemax changed from 12 to 13\n\
+             age group 2 of event 10 added\n\
+             event 11 removed\n\
+             event 12 added\n",
+            diff.to_string()
+        );
+    }
+
+    #[test]
+    fn diff_fees_of_same_type() {
+        let mut old = invitation();
+        old.meets[0]
+            .add_fee(Fee::untyped(300))
+            .add_fee(Fee::untyped(400));
+        let mut new = old.clone();
+
+        let meet = &mut new.meets[0];
+        meet.fees_mut()[2] = Fee::untyped(450);
+        meet.add_fee(Fee::new(FeeType::Athlete, 500));
+
+        assert_eq!(
+            "fee #2: value changed from 400 to 450\n\
+             fee ATHLETE #2 added\n",
+            diff(&old, &new).to_string()
+        );
+    }
+
+    #[test]
+    fn diff_age_date_and_handicap() {
+        let mut old = invitation();
+        old.meets[0].with_age_date(AgeDate {
+            value: NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+            r#type: AgeDateType::Year,
+            ..Default::default()
+        });
+        let mut new = old.clone();
+
+        let meet = &mut new.meets[0];
+        meet.with_age_date(AgeDate {
+            value: NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+            r#type: AgeDateType::Date,
+            ..Default::default()
+        });
+        meet.sessions_mut()[0].events_mut()[0].age_groups_mut()[0].handicap =
+            Some("5,14".parse::<SportClasses>().unwrap());
+
+        assert_eq!(
+            "meet 1: AGEDATE type changed from YEAR to DATE\n\
+             age group 1 of event 10: handicap set to 5,14\n",
+            diff(&old, &new).to_string()
+        );
+    }
+}
@@ -0,0 +1,420 @@
+use std::collections::BTreeSet;
+
+use crate::error::EntryError;
+
+use super::{athlete::Athlete, club::Club, event::Event, lenex::Lenex, meet::Meet, relay::Relay};
+
+/// Builds the entries document of a club for a meet invitation.
+///
+/// Events are selected with [`EntriesBuilder::add_event`] before athletes and relays are entered
+/// in them. Every entry is checked against the gender and age groups of its event, so that the
+/// built document can be imported as is by meet managers.
+#[derive(Debug, Clone)]
+pub struct EntriesBuilder<'a> {
+    meet: &'a Meet,
+    club: Club,
+    events: BTreeSet<u32>,
+}
+
+impl<'a> EntriesBuilder<'a> {
+    /// Starts the entries of `club`, given without athletes nor relays, for the first meet of
+    /// `invitation`.
+    pub fn new(invitation: &'a Lenex, club: Club) -> crate::Result<Self> {
+        let meet = invitation.meets.first().ok_or(EntryError::NoMeet)?;
+
+        Ok(Self {
+            meet,
+            club,
+            events: BTreeSet::new(),
+        })
+    }
+
+    pub fn club(&self) -> &Club {
+        &self.club
+    }
+
+    pub fn add_event(&mut self, event_id: u32) -> crate::Result<&mut Self> {
+        self.event(event_id)?;
+        self.events.insert(event_id);
+
+        Ok(self)
+    }
+
+    /// Adds the athlete with its entries, or nothing if one of them is refused.
+    pub fn add_athlete(&mut self, athlete: Athlete) -> crate::Result<&mut Self> {
+        for entry in athlete.entries() {
+            let event = self.selected_event(entry.event_id())?;
+
+            if event.swim_style().relay_count > 1 {
+                return Err(EntryError::RelayEvent {
+                    athlete_id: athlete.id(),
+                    event_id: event.id,
+                }
+                .into());
+            }
+
+            if !event.accepts_gender(athlete.gender()) {
+                return Err(EntryError::Gender {
+                    competitor: athlete.id(),
+                    event_id: event.id,
+                }
+                .into());
+            }
+
+            if !event.accepts_handicap(&athlete) {
+                return Err(EntryError::Handicap {
+                    athlete_id: athlete.id(),
+                    event_id: event.id,
+                }
+                .into());
+            }
+
+            if !self.fits_age_group(&athlete, event) {
+                return Err(EntryError::AgeGroup {
+                    competitor: athlete.id(),
+                    event_id: event.id,
+                }
+                .into());
+            }
+        }
+
+        self.club.add_athlete(athlete);
+
+        Ok(self)
+    }
+
+    /// Adds the relay with its entries, or nothing if one of them is refused.
+    pub fn add_relay(&mut self, relay: Relay) -> crate::Result<&mut Self> {
+        for entry in relay.entries().iter() {
+            let event = self.selected_event(entry.event_id())?;
+
+            if event.swim_style().relay_count <= 1 {
+                return Err(EntryError::IndividualEvent { event_id: event.id }.into());
+            }
+
+            if !event.accepts_gender(relay.gender()) {
+                return Err(EntryError::Gender {
+                    competitor: relay.number().unwrap_or_default(),
+                    event_id: event.id,
+                }
+                .into());
+            }
+
+            let athletes: Vec<Option<&Athlete>> = self
+                .club
+                .relay_athletes(entry.relay_positions())
+                .into_iter()
+                .map(|(_, athlete)| athlete)
+                .collect();
+            if !self.fits_relay_age_group(&athletes, event) {
+                return Err(EntryError::AgeGroup {
+                    competitor: relay.number().unwrap_or_default(),
+                    event_id: event.id,
+                }
+                .into());
+            }
+        }
+
+        self.club.add_relay(relay);
+
+        Ok(self)
+    }
+
+    /// The entries document: the meet header, the club and the events it entered.
+    ///
+    /// The fees of the meet are kept to bill the entries, while its officials and the judges of
+    /// its sessions are left out.
+    pub fn build(&self) -> Lenex {
+        let referenced: BTreeSet<u32> = self
+            .club
+            .athletes()
+            .iter()
+            .flat_map(|athlete| athlete.entries())
+            .chain(
+                self.club
+                    .relays()
+                    .iter()
+                    .flat_map(|relay| relay.entries().iter()),
+            )
+            .map(|entry| entry.event_id())
+            .collect();
+
+        let mut meet = self.meet.clone();
+        meet.officials_mut().clear();
+        for session in meet.sessions_mut() {
+            session.judges_mut().clear();
+            session
+                .events_mut()
+                .retain(|event| referenced.contains(&event.id));
+        }
+        meet.sessions_mut()
+            .retain(|session| !session.events().is_empty());
+        *meet.clubs_mut() = vec![self.club.clone()];
+
+        let mut lenex = Lenex::new();
+        lenex.meets.push(meet);
+
+        lenex
+    }
+
+    fn event(&self, event_id: u32) -> Result<&'a Event, EntryError> {
+        self.meet
+            .sessions()
+            .iter()
+            .flat_map(|session| session.events())
+            .find(|event| event.id == event_id)
+            .ok_or(EntryError::UnknownEvent(event_id))
+    }
+
+    fn selected_event(&self, event_id: u32) -> Result<&'a Event, EntryError> {
+        let event = self.event(event_id)?;
+
+        match self.events.contains(&event_id) {
+            true => Ok(event),
+            false => Err(EntryError::EventNotSelected(event_id)),
+        }
+    }
+
+    fn fits_age_group(&self, athlete: &Athlete, event: &Event) -> bool {
+        event.age_groups().is_empty()
+            || self
+                .meet
+                .effective_age_date()
+                .is_none_or(|age_date| event.age_group_for(athlete, &age_date).is_some())
+    }
+
+    /// Whether the relay swum by `athletes` fits an age group of the event. Relays whose
+    /// swimmers are not all known yet are accepted.
+    fn fits_relay_age_group(&self, athletes: &[Option<&Athlete>], event: &Event) -> bool {
+        let Some(athletes) = athletes.iter().copied().collect::<Option<Vec<&Athlete>>>() else {
+            return true;
+        };
+
+        event.age_groups().is_empty()
+            || athletes.is_empty()
+            || self.meet.effective_age_date().is_none_or(|age_date| {
+                event
+                    .age_groups()
+                    .iter()
+                    .any(|age_group| age_group.accepts_relay(&athletes, &age_date))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::{
+        model::{
+            AgeDate, AgeDateType, AgeGroup, Calculate, Entry, Fee, FeeType, Gender, Handicap,
+            Judge, JudgeRole, Official, RelayPosition, Session, SportClass, SportClasses, Stroke,
+            SwimStyle,
+        },
+        Error,
+    };
+
+    fn invitation() -> Lenex {
+        let mut girls = Event::new(1, 1, SwimStyle::new(1, 50, 1, Stroke::Free));
+        girls
+            .with_gender(Gender::Female)
+            .add_age_group(AgeGroup::new(1, Some(11), Some(12)));
+
+        let mut relay = Event::new(3, 3, SwimStyle::new(3, 50, 4, Stroke::Free));
+        relay.with_gender(Gender::Female);
+
+        let session = Session::new(
+            1,
+            NaiveDate::from_ymd_opt(2023, 2, 11).unwrap(),
+            vec![
+                girls,
+                Event::new(2, 2, SwimStyle::new(2, 100, 1, Stroke::Back)),
+                relay,
+            ],
+        );
+        let mut meet = Meet::new(
+            "Meet".into(),
+            "CAN".into(),
+            "Montreal".into(),
+            vec![session],
+        );
+        meet.with_age_date(AgeDate {
+            value: NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+            r#type: AgeDateType::Year,
+            ..Default::default()
+        })
+        .add_fee(Fee::new(FeeType::Athlete, 1500))
+        .add_official(Official::new(1, "John".into(), "Smith".into()));
+        meet.sessions_mut()[0].add_judge(Judge::new(1, JudgeRole::Referee));
+
+        let mut lenex = Lenex::new();
+        lenex.meets.push(meet);
+
+        lenex
+    }
+
+    fn athlete(id: u32, gender: Gender, year: i32, event_id: u32) -> Athlete {
+        let mut athlete = Athlete::new(
+            id,
+            "Jane".into(),
+            "Doe".into(),
+            gender,
+            NaiveDate::from_ymd_opt(year, 6, 1).unwrap().into(),
+        );
+        athlete.add_entry(Entry::new(event_id));
+
+        athlete
+    }
+
+    #[test]
+    fn build_entries() {
+        let invitation = invitation();
+        let mut builder = EntriesBuilder::new(&invitation, Club::new(1, "Club".into())).unwrap();
+        builder.add_event(1).unwrap().add_event(3).unwrap();
+        builder
+            .add_athlete(athlete(1, Gender::Female, 2012, 1))
+            .unwrap();
+
+        let mut relay = Relay::new(1, Gender::Female);
+        relay.add_entry(Entry::new(3));
+        builder.add_relay(relay).unwrap();
+
+        let lenex = builder.build();
+        let meet = &lenex.meets[0];
+        assert_eq!("Meet", meet.name());
+        assert_eq!(1, meet.clubs().len());
+        assert_eq!(1, meet.clubs()[0].athletes().len());
+
+        let events: Vec<u32> = meet.sessions()[0]
+            .events()
+            .iter()
+            .map(|event| event.id)
+            .collect();
+        assert_eq!(vec![1, 3], events);
+
+        assert_eq!(1, meet.fees().len());
+        assert!(meet.officials().is_empty());
+        assert!(meet.sessions()[0].judges().is_empty());
+    }
+
+    #[test]
+    fn refuse_entries() {
+        let invitation = invitation();
+        let mut builder = EntriesBuilder::new(&invitation, Club::new(1, "Club".into())).unwrap();
+        builder.add_event(1).unwrap().add_event(3).unwrap();
+
+        let refused = |result: crate::Result<&mut EntriesBuilder>| match result {
+            Err(Error::Entry(error)) => error,
+            _ => panic!("the entry should be refused"),
+        };
+
+        assert_eq!(EntryError::UnknownEvent(9), refused(builder.add_event(9)));
+        assert_eq!(
+            EntryError::EventNotSelected(2),
+            refused(builder.add_athlete(athlete(1, Gender::Female, 2012, 2)))
+        );
+        assert_eq!(
+            EntryError::Gender {
+                competitor: 2,
+                event_id: 1
+            },
+            refused(builder.add_athlete(athlete(2, Gender::Male, 2012, 1)))
+        );
+        assert_eq!(
+            EntryError::AgeGroup {
+                competitor: 3,
+                event_id: 1
+            },
+            refused(builder.add_athlete(athlete(3, Gender::Female, 2010, 1)))
+        );
+        assert_eq!(
+            EntryError::RelayEvent {
+                athlete_id: 4,
+                event_id: 3
+            },
+            refused(builder.add_athlete(athlete(4, Gender::Female, 2012, 3)))
+        );
+        assert!(builder.club().athletes().is_empty());
+    }
+
+    #[test]
+    fn refuse_sport_class() {
+        let mut invitation = invitation();
+        let event = &mut invitation.meets[0].sessions_mut()[0].events_mut()[1];
+        event.with_handicap(SportClasses(vec![
+            SportClass::Class(9),
+            SportClass::Class(10),
+        ]));
+        event.add_age_group(AgeGroup {
+            handicap: Some(SportClasses(vec![SportClass::Class(10)])),
+            ..AgeGroup::new(2, None, None)
+        });
+
+        let mut builder = EntriesBuilder::new(&invitation, Club::new(1, "Club".into())).unwrap();
+        builder.add_event(2).unwrap();
+
+        let para_athlete = |id, class| {
+            let mut athlete = athlete(id, Gender::Female, 2012, 2);
+            athlete.with_handicap(Handicap {
+                free: Some(SportClass::Class(class)),
+                ..Default::default()
+            });
+
+            athlete
+        };
+
+        assert!(matches!(
+            builder.add_athlete(athlete(1, Gender::Female, 2012, 2)),
+            Err(Error::Entry(EntryError::Handicap {
+                athlete_id: 1,
+                event_id: 2
+            }))
+        ));
+        assert!(matches!(
+            builder.add_athlete(para_athlete(2, 9)),
+            Err(Error::Entry(EntryError::AgeGroup {
+                competitor: 2,
+                event_id: 2
+            }))
+        ));
+        assert!(builder.add_athlete(para_athlete(3, 10)).is_ok());
+    }
+
+    #[test]
+    fn relay_total_age_group() {
+        let mut invitation = invitation();
+        invitation.meets[0].sessions_mut()[0].events_mut()[2].add_age_group(AgeGroup {
+            calculate: Calculate::Total,
+            ..AgeGroup::new(2, Some(45), Some(49))
+        });
+
+        let mut builder = EntriesBuilder::new(&invitation, Club::new(1, "Club".into())).unwrap();
+        builder.add_event(3).unwrap();
+        for (id, year) in [(1, 2012), (2, 2011), (3, 2011), (4, 2010)] {
+            let mut swimmer = athlete(id, Gender::Female, year, 3);
+            swimmer.entries_mut().clear();
+            builder.add_athlete(swimmer).unwrap();
+        }
+
+        let relay = |ids: &[u32]| {
+            let mut entry = Entry::new(3);
+            for (number, id) in ids.iter().enumerate() {
+                entry.add_relay_position(RelayPosition::new(*id, number as u32 + 1));
+            }
+
+            let mut relay = Relay::new(1, Gender::Female);
+            relay.add_entry(entry);
+            relay
+        };
+
+        assert!(builder.add_relay(relay(&[1, 2, 3, 4])).is_ok());
+        assert!(matches!(
+            builder.add_relay(relay(&[1, 1, 1, 1])),
+            Err(Error::Entry(EntryError::AgeGroup {
+                competitor: 1,
+                event_id: 3
+            }))
+        ));
+    }
+}
@@ -1,26 +1,127 @@
-use chrono::Duration;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, PartialEq, Default, Debug)]
+use crate::{
+    collection::Collection,
+    serialization::preserve::{preserve_collection, restore_collection, Node, Preserve},
+};
+
+use super::{extra::Extra, relay_position::RelayPosition, swim_time::SwimTime};
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
 #[serde(rename = "ENTRY")]
 pub struct Entry {
     #[serde(rename = "eventid")]
     event_id: u32,
 
+    #[serde(rename = "entrytime", default)]
+    entry_time: Option<SwimTime>,
+
+    #[serde(rename = "heatid")]
+    heat_id: Option<u32>,
+
+    lane: Option<u32>,
+
     #[serde(
-        rename = "entrytime",
+        rename = "RELAYPOSITIONS",
         default,
-        with = "crate::serialization::serde_time::swim_time"
+        skip_serializing_if = "Vec::is_empty"
     )]
-    entry_time: Option<Duration>,
+    relay_positions: Collection<RelayPosition>,
+
+    #[serde(skip)]
+    extra: Extra,
+}
+
+impl Entry {
+    pub fn new(event_id: u32) -> Self {
+        Self {
+            event_id,
+            ..Default::default()
+        }
+    }
+
+    pub fn event_id(&self) -> u32 {
+        self.event_id
+    }
+
+    pub fn entry_time(&self) -> Option<SwimTime> {
+        self.entry_time
+    }
+
+    pub fn heat_id(&self) -> Option<u32> {
+        self.heat_id
+    }
+
+    pub fn lane(&self) -> Option<u32> {
+        self.lane
+    }
+
+    pub fn relay_positions(&self) -> &[RelayPosition] {
+        &self.relay_positions
+    }
+
+    pub fn relay_positions_mut(&mut self) -> &mut Vec<RelayPosition> {
+        &mut self.relay_positions
+    }
+
+    pub(crate) fn with_event_id(&mut self, event_id: u32) -> &mut Self {
+        self.event_id = event_id;
+
+        self
+    }
+
+    pub fn with_entry_time(&mut self, time: SwimTime) -> &mut Self {
+        self.entry_time = Some(time);
+
+        self
+    }
+
+    pub fn with_heat_id(&mut self, heat_id: u32) -> &mut Self {
+        self.heat_id = Some(heat_id);
+
+        self
+    }
+
+    pub fn with_lane(&mut self, lane: u32) -> &mut Self {
+        self.lane = Some(lane);
+
+        self
+    }
+
+    pub fn add_relay_position(&mut self, relay_position: RelayPosition) -> &mut Self {
+        self.relay_positions.push(relay_position);
+
+        self
+    }
+
+    /// The attributes and child elements of this element unknown to the model.
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
+}
+
+impl Preserve for Entry {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_collection(&mut self.relay_positions, node, "RELAYPOSITIONS");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_collection(&self.relay_positions, node, "RELAYPOSITIONS");
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use fast_xml::{de, se};
 
-    use crate::collection::Collection;
-
     use super::*;
 
     #[test]
@@ -34,8 +135,17 @@ mod tests {
 
         let first = entries.first().unwrap();
         assert_eq!(150, first.event_id);
-        assert_eq!(1, first.entry_time.unwrap().num_seconds());
-        assert_eq!(1250, first.entry_time.unwrap().num_milliseconds());
+        assert_eq!(Some(SwimTime::Time(125)), first.entry_time);
+    }
+
+    #[test]
+    fn deserialize_heat_and_lane() {
+        let result = de::from_str::<Entry>(r#"<ENTRY eventid="150" heatid="1502" lane="3"/>"#);
+        assert!(result.is_ok());
+
+        let entry = result.unwrap();
+        assert_eq!(Some(1502), entry.heat_id);
+        assert_eq!(Some(3), entry.lane);
     }
 
     #[test]
@@ -47,7 +157,7 @@ mod tests {
             },
             Entry {
                 event_id: 48,
-                entry_time: Some(Duration::seconds(5) + Duration::milliseconds(500)),
+                entry_time: Some(SwimTime::Time(550)),
                 ..Default::default()
             },
         ]);
@@ -56,4 +166,14 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!("<ENTRIES><ENTRY eventid=\"64\"/><ENTRY eventid=\"48\" entrytime=\"00:00:05.50\"/></ENTRIES>", result.unwrap());
     }
+
+    #[test]
+    fn round_trip_entry_times() {
+        let xml = r#"<ENTRIES><ENTRY eventid="1" entrytime="NT"/><ENTRY eventid="2" entrytime="00:17:05.43"/></ENTRIES>"#;
+        let entries = de::from_str::<Collection<Entry>>(xml).unwrap();
+
+        assert_eq!(Some(SwimTime::NoTime), entries[0].entry_time());
+        assert_eq!(SwimTime::from_hms(0, 17, 5, 43), entries[1].entry_time());
+        assert_eq!(xml, se::to_string(&entries).unwrap());
+    }
 }
@@ -1,11 +1,31 @@
 use chrono::NaiveTime;
 use serde::{Deserialize, Serialize};
 
-use crate::{collection::Collection, serialization::serde_time};
+use crate::{
+    collection::Collection,
+    serialization::{
+        preserve::{
+            preserve_child, preserve_collection, restore_child, restore_collection, Node, Preserve,
+        },
+        serde_time,
+    },
+};
 
-use super::{age_group::AgeGroup, gender::Gender, round::Round, swimstyle::SwimStyle};
+use super::{
+    age_date::AgeDate,
+    age_group::AgeGroup,
+    athlete::Athlete,
+    extra::Extra,
+    fee::Fee,
+    gender::Gender,
+    handicap::{SportClass, SportClasses},
+    heat::Heat,
+    round::Round,
+    swimstyle::SwimStyle,
+    time_standard_ref::TimeStandardRef,
+};
 
-#[derive(Serialize, Deserialize, PartialEq, Default, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
 #[serde(rename = "EVENT")]
 pub struct Event {
     #[serde(rename = "eventid")]
@@ -31,11 +51,30 @@ pub struct Event {
 
     round: Option<Round>,
 
+    handicap: Option<SportClasses>,
+
     #[serde(rename = "SWIMSTYLE")]
     swim_style: SwimStyle,
 
     #[serde(rename = "AGEGROUPS", default, skip_serializing_if = "Vec::is_empty")]
     age_groups: Collection<AgeGroup>,
+
+    /// The fee charged for each entry to this event.
+    #[serde(rename = "FEE")]
+    fee: Option<Fee>,
+
+    #[serde(rename = "HEATS", default, skip_serializing_if = "Vec::is_empty")]
+    heats: Collection<Heat>,
+
+    #[serde(
+        rename = "TIMESTANDARDREFS",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    time_standard_refs: Collection<TimeStandardRef>,
+
+    #[serde(skip)]
+    extra: Extra,
 }
 
 impl Event {
@@ -47,7 +86,171 @@ impl Event {
             ..Default::default()
         }
     }
+
+    pub fn day_time(&self) -> Option<NaiveTime> {
+        self.day_time
+    }
+
+    pub fn gender(&self) -> Option<&Gender> {
+        self.gender.as_ref()
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn round(&self) -> Option<&Round> {
+        self.round.as_ref()
+    }
+
+    /// The sport classes of the athletes this event is restricted to.
+    pub fn handicap(&self) -> Option<&SportClasses> {
+        self.handicap.as_ref()
+    }
+
+    pub fn swim_style(&self) -> &SwimStyle {
+        &self.swim_style
+    }
+
+    pub fn fee(&self) -> Option<&Fee> {
+        self.fee.as_ref()
+    }
+
+    pub fn age_groups(&self) -> &[AgeGroup] {
+        &self.age_groups
+    }
+
+    pub fn age_groups_mut(&mut self) -> &mut Vec<AgeGroup> {
+        &mut self.age_groups
+    }
+
+    pub fn heats(&self) -> &[Heat] {
+        &self.heats
+    }
+
+    pub fn heats_mut(&mut self) -> &mut Vec<Heat> {
+        &mut self.heats
+    }
+
+    pub fn time_standard_refs(&self) -> &[TimeStandardRef] {
+        &self.time_standard_refs
+    }
+
+    pub fn time_standard_refs_mut(&mut self) -> &mut Vec<TimeStandardRef> {
+        &mut self.time_standard_refs
+    }
+
+    pub fn with_day_time(&mut self, time: NaiveTime) -> &mut Self {
+        self.day_time = Some(time);
+
+        self
+    }
+
+    pub fn with_gender(&mut self, gender: Gender) -> &mut Self {
+        self.gender = Some(gender);
+
+        self
+    }
+
+    pub fn with_round(&mut self, round: Round) -> &mut Self {
+        self.round = Some(round);
+
+        self
+    }
+
+    pub fn with_handicap(&mut self, handicap: SportClasses) -> &mut Self {
+        self.handicap = Some(handicap);
+
+        self
+    }
+
+    pub fn with_fee(&mut self, fee: Fee) -> &mut Self {
+        self.fee = Some(fee);
+
+        self
+    }
+
+    pub fn add_age_group(&mut self, age_group: AgeGroup) -> &mut Self {
+        self.age_groups.push(age_group);
+
+        self
+    }
+
+    pub fn add_heat(&mut self, heat: Heat) -> &mut Self {
+        self.heats.push(heat);
+
+        self
+    }
+
+    pub fn add_time_standard_ref(&mut self, time_standard_ref: TimeStandardRef) -> &mut Self {
+        self.time_standard_refs.push(time_standard_ref);
+
+        self
+    }
+
+    /// Whether athletes, relays or lists of `gender` can take part in this event.
+    ///
+    /// Events without a gender, open to all or mixed accept every gender.
+    pub fn accepts_gender(&self, gender: &Gender) -> bool {
+        match (&self.gender, gender) {
+            (None | Some(Gender::All) | Some(Gender::Mixed), _) | (_, Gender::All) => true,
+            (Some(event_gender), gender) => event_gender == gender,
+        }
+    }
+
+    /// The sport class of the athlete for the stroke of this event.
+    pub fn sport_class(&self, athlete: &Athlete) -> Option<SportClass> {
+        athlete.handicap()?.class_for(&self.swim_style.stroke)
+    }
+
+    /// Whether the sport class of the athlete is one this event is open to.
+    pub fn accepts_handicap(&self, athlete: &Athlete) -> bool {
+        self.handicap
+            .as_ref()
+            .is_none_or(|classes| classes.admits(self.sport_class(athlete)))
+    }
+
+    /// The first age group of this event the athlete can swim in, by gender, age and sport class.
+    pub fn age_group_for(&self, athlete: &Athlete, age_date: &AgeDate) -> Option<&AgeGroup> {
+        let class = self.sport_class(athlete);
+
+        self.age_groups.iter().find(|age_group| {
+            age_group.accepts(athlete, age_date) && age_group.accepts_sport_class(class)
+        })
+    }
+
+    /// The attributes and child elements of this element unknown to the model.
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
+
+impl Preserve for Event {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_child(Some(&mut self.swim_style), node, "SWIMSTYLE");
+        preserve_collection(&mut self.age_groups, node, "AGEGROUPS");
+        preserve_child(self.fee.as_mut(), node, "FEE");
+        preserve_collection(&mut self.heats, node, "HEATS");
+        preserve_collection(&mut self.time_standard_refs, node, "TIMESTANDARDREFS");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_child(Some(&self.swim_style), node, "SWIMSTYLE");
+        restore_collection(&self.age_groups, node, "AGEGROUPS");
+        restore_child(self.fee.as_ref(), node, "FEE");
+        restore_collection(&self.heats, node, "HEATS");
+        restore_collection(&self.time_standard_refs, node, "TIMESTANDARDREFS");
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use fast_xml::{de, se};
@@ -72,6 +275,40 @@ mod tests {
         assert_eq!(456, event.number);
     }
 
+    #[test]
+    fn deserialize_fee() {
+        let result = de::from_str::<Event>(
+            r#"<EVENT eventid="1" number="1"><SWIMSTYLE distance="50" relaycount="1" swimstyleid="1" stroke="FREE"/><FEE currency="CHF" value="1200"/></EVENT>"#,
+        );
+        assert!(result.is_ok());
+
+        let fee = result.unwrap().fee.unwrap();
+        assert_eq!(Some("CHF"), fee.currency());
+        assert_eq!(1200, fee.value());
+    }
+
+    #[test]
+    fn serialize_built() {
+        let mut event = Event::new(123, 4, SwimStyle::default());
+        event
+            .with_gender(Gender::Female)
+            .with_round(Round::Prelims)
+            .add_age_group(AgeGroup {
+                id: 1,
+                ..Default::default()
+            });
+
+        assert_eq!(Some(&Gender::Female), event.gender());
+        assert_eq!(1, event.age_groups().len());
+
+        let result = se::to_string(&event);
+        assert!(result.is_ok());
+        assert_eq!(
+            r#"<EVENT eventid="123" gender="F" number="4" round="PRE"><SWIMSTYLE swimstyleid="0" distance="0" relaycount="0" stroke="UNKNOWN"/><AGEGROUPS><AGEGROUP agegroupid="1" agemin="-1" agemax="-1"/></AGEGROUPS></EVENT>"#,
+            result.unwrap()
+        );
+    }
+
     #[test]
     fn serialize_basic_collection() {
         let events = vec![Event {
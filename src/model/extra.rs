@@ -0,0 +1,66 @@
+/// Attributes and child elements of an element that the model does not know about.
+///
+/// Only filled by the lossless readers, such as [`crate::from_bytes_lossless`], and written back
+/// by [`super::Lenex::xml`] when the document was read by one of them.
+///
+/// Extras never make two elements unequal, so documents read with and without a lossless reader
+/// compare equal.
+#[derive(Default, Debug, Clone)]
+pub struct Extra {
+    /// Unknown attributes as name and value, in document order.
+    pub attributes: Vec<(Position, String, String)>,
+
+    /// Unknown child elements as raw XML, in document order.
+    pub elements: Vec<(Position, String)>,
+}
+
+/// Where an unknown attribute or child element stood in its element, by the names of the
+/// attributes or child elements before it, nearest first.
+///
+/// It is written back after the nearest of them still in the element, or first when none is,
+/// so that elements the model drops, such as empty collections, do not move it.
+#[derive(PartialEq, Eq, Default, Debug, Clone)]
+pub struct Position {
+    pub after: Vec<String>,
+}
+
+impl Extra {
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty() && self.elements.is_empty()
+    }
+}
+
+impl Position {
+    /// The position after `names`, the attributes or child elements before it in document
+    /// order.
+    pub(crate) fn after<'a>(names: impl DoubleEndedIterator<Item = &'a str>) -> Self {
+        Position {
+            after: names.rev().map(str::to_string).collect(),
+        }
+    }
+
+    /// The index to insert at among `names`, the attributes or child elements of the element.
+    pub(crate) fn index<'a>(&self, names: impl Iterator<Item = &'a str> + Clone) -> usize {
+        self.after
+            .iter()
+            .enumerate()
+            .find_map(|(i, name)| {
+                // Elements of the same name before this one, such as the items of a collection.
+                let occurrence = self.after[i + 1..].iter().filter(|n| *n == name).count();
+
+                names
+                    .clone()
+                    .enumerate()
+                    .filter(|(_, n)| n == name)
+                    .nth(occurrence)
+                    .map(|(index, _)| index + 1)
+            })
+            .unwrap_or(0)
+    }
+}
+
+impl PartialEq for Extra {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
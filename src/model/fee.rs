@@ -1,7 +1,113 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, PartialEq, Default, Debug)]
+use crate::serialization::preserve::Preserve;
+
+pub use self::fee_type::FeeType;
+
+use super::extra::Extra;
+
+mod fee_type;
+
+/// An amount to pay, in cents of its currency.
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[serde(rename = "FEE")]
 pub struct Fee {
-    r#type: String,
+    /// The ISO 4217 code of the currency, such as `CAD` or `EUR`.
+    currency: Option<String>,
+
+    #[serde(default)]
+    r#type: Option<FeeType>,
+
     value: u64,
+
+    #[serde(skip)]
+    extra: Extra,
+}
+
+impl Fee {
+    pub fn new(r#type: FeeType, value: u64) -> Self {
+        Self {
+            r#type: Some(r#type),
+            value,
+            ..Default::default()
+        }
+    }
+
+    /// A fee without type, as charged for the entries to an event or as the penalty of a time
+    /// standard.
+    pub fn untyped(value: u64) -> Self {
+        Self {
+            value,
+            ..Default::default()
+        }
+    }
+
+    pub fn currency(&self) -> Option<&str> {
+        self.currency.as_deref()
+    }
+
+    /// The type of the fee, missing for event fees and penalty fees of time standards.
+    pub fn r#type(&self) -> Option<&FeeType> {
+        self.r#type.as_ref()
+    }
+
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn with_currency(&mut self, currency: String) -> &mut Self {
+        self.currency = Some(currency);
+
+        self
+    }
+
+    /// The attributes and child elements of this element unknown to the model.
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
+}
+
+impl Preserve for Fee {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fast_xml::{de, se};
+
+    use super::*;
+
+    #[test]
+    fn deserialize_basic() {
+        let result =
+            de::from_str::<Fee>(r#"<FEE currency="CAD" type="LATEENTRY.RELAY" value="2500"/>"#);
+        assert!(result.is_ok());
+
+        let fee = result.unwrap();
+        assert_eq!(Some("CAD"), fee.currency());
+        assert_eq!(Some(&FeeType::LateEntryRelay), fee.r#type());
+        assert_eq!(2500, fee.value());
+
+        let fee = de::from_str::<Fee>(r#"<FEE value="800"/>"#).unwrap();
+        assert_eq!(None, fee.r#type());
+    }
+
+    #[test]
+    fn serialize_built() {
+        let mut fee = Fee::new(FeeType::Club, 5000);
+        fee.with_currency("EUR".into());
+
+        let result = se::to_string(&fee);
+        assert!(result.is_ok());
+        assert_eq!(
+            r#"<FEE currency="EUR" type="CLUB" value="5000"/>"#,
+            result.unwrap()
+        );
+    }
 }
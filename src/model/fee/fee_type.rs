@@ -0,0 +1,100 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// What a meet fee is charged for.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
+pub enum FeeType {
+    /// Charged once per club.
+    Club,
+
+    /// Charged once per athlete with at least one entry.
+    Athlete,
+
+    /// Charged once per relay team with at least one entry.
+    Relay,
+
+    /// Charged once per team, each club element standing for one team.
+    Team,
+
+    /// Charged on top of the event fee for each individual entry sent late.
+    LateEntryIndividual,
+
+    /// Charged on top of the event fee for each relay entry sent late.
+    LateEntryRelay,
+
+    /// A type the specification does not define, kept as written.
+    Other(String),
+}
+
+impl Display for FeeType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FeeType::Club => "CLUB",
+            FeeType::Athlete => "ATHLETE",
+            FeeType::Relay => "RELAY",
+            FeeType::Team => "TEAM",
+            FeeType::LateEntryIndividual => "LATEENTRY.INDIVIDUAL",
+            FeeType::LateEntryRelay => "LATEENTRY.RELAY",
+            FeeType::Other(r#type) => r#type,
+        })
+    }
+}
+
+impl From<&str> for FeeType {
+    fn from(value: &str) -> Self {
+        match value {
+            "CLUB" => FeeType::Club,
+            "ATHLETE" => FeeType::Athlete,
+            "RELAY" => FeeType::Relay,
+            "TEAM" => FeeType::Team,
+            "LATEENTRY.INDIVIDUAL" => FeeType::LateEntryIndividual,
+            "LATEENTRY.RELAY" => FeeType::LateEntryRelay,
+            r#type => FeeType::Other(r#type.to_string()),
+        }
+    }
+}
+
+impl Serialize for FeeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FeeType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(String::deserialize(deserializer)?.as_str().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fast_xml::{de, se};
+
+    #[test]
+    fn serialize() {
+        let value = FeeType::LateEntryIndividual;
+        let result = se::to_string(&value);
+        assert!(result.is_ok());
+
+        assert_eq!("LATEENTRY.INDIVIDUAL", result.unwrap());
+    }
+
+    #[test]
+    fn deserialize() {
+        let result = de::from_str::<FeeType>("LATEENTRY.RELAY");
+        assert!(result.is_ok());
+        assert_eq!(FeeType::LateEntryRelay, result.unwrap());
+
+        let result = de::from_str::<FeeType>("CAN.TRANSFER");
+        assert!(result.is_ok());
+        assert_eq!(FeeType::Other("CAN.TRANSFER".into()), result.unwrap());
+    }
+}
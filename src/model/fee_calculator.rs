@@ -0,0 +1,255 @@
+use std::collections::BTreeMap;
+
+use super::{
+    club::Club,
+    entry::Entry,
+    event::Event,
+    fee::{Fee, FeeType},
+    meet::Meet,
+};
+
+/// Sums the fees a club owes to a meet for its entries.
+///
+/// Club and team fees are charged once, athlete and relay fees for each athlete and relay with at
+/// least one entry, and event fees for each entry to their event. Late entry fees are only charged
+/// when the entries are late, for each entry. Fees of types the specification does not define are
+/// left out, as are the penalty fees of time standards.
+#[derive(Debug, Clone, Copy)]
+pub struct FeeCalculator<'a> {
+    meet: &'a Meet,
+    late: bool,
+}
+
+/// The fees owed by a club, as returned by [`FeeCalculator::club`].
+#[derive(PartialEq, Default, Debug, Clone)]
+pub struct FeeStatement<'a> {
+    pub items: Vec<FeeItem<'a>>,
+}
+
+/// One fee of a [`FeeStatement`] and how many times it is charged.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct FeeItem<'a> {
+    pub fee: &'a Fee,
+
+    /// The event of an event fee, `None` for meet fees.
+    pub event_id: Option<u32>,
+
+    pub count: u64,
+}
+
+impl<'a> FeeCalculator<'a> {
+    pub fn new(meet: &'a Meet) -> Self {
+        Self { meet, late: false }
+    }
+
+    /// Whether the entries are sent after the deadline of the meet.
+    pub fn with_late_entries(&mut self, late: bool) -> &mut Self {
+        self.late = late;
+
+        self
+    }
+
+    /// The fees owed by `club` for the entries of its athletes and relays, nothing when it has
+    /// none.
+    pub fn club(&self, club: &Club) -> FeeStatement<'a> {
+        let individual: Vec<&Entry> = club
+            .athletes()
+            .iter()
+            .flat_map(|athlete| athlete.entries())
+            .collect();
+        let relay: Vec<&Entry> = club
+            .relays()
+            .iter()
+            .flat_map(|relay| relay.entries().iter())
+            .collect();
+        if individual.is_empty() && relay.is_empty() {
+            return FeeStatement::default();
+        }
+
+        let athletes = club
+            .athletes()
+            .iter()
+            .filter(|athlete| !athlete.entries().is_empty())
+            .count();
+        let relays = club
+            .relays()
+            .iter()
+            .filter(|relay| !relay.entries().is_empty())
+            .count();
+
+        let mut items = Vec::new();
+        for fee in self.meet.fees() {
+            let count = match fee.r#type() {
+                Some(FeeType::Club | FeeType::Team) => 1,
+                Some(FeeType::Athlete) => athletes,
+                Some(FeeType::Relay) => relays,
+                Some(FeeType::LateEntryIndividual) if self.late => individual.len(),
+                Some(FeeType::LateEntryRelay) if self.late => relay.len(),
+                _ => 0,
+            };
+
+            if count > 0 {
+                items.push(FeeItem {
+                    fee,
+                    event_id: None,
+                    count: count as u64,
+                });
+            }
+        }
+
+        let mut entries: BTreeMap<u32, u64> = BTreeMap::new();
+        for entry in individual.iter().chain(relay.iter()) {
+            *entries.entry(entry.event_id()).or_default() += 1;
+        }
+        for (event_id, count) in entries {
+            if let Some(fee) = self.event(event_id).and_then(Event::fee) {
+                items.push(FeeItem {
+                    fee,
+                    event_id: Some(event_id),
+                    count,
+                });
+            }
+        }
+
+        FeeStatement { items }
+    }
+
+    fn event(&self, event_id: u32) -> Option<&'a Event> {
+        self.meet
+            .sessions()
+            .iter()
+            .flat_map(|session| session.events())
+            .find(|event| event.id == event_id)
+    }
+}
+
+impl FeeStatement<'_> {
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The sum of the fees for each currency, in cents. Fees without currency are summed under
+    /// `None`.
+    pub fn totals(&self) -> Vec<(Option<&str>, u64)> {
+        let mut totals: BTreeMap<Option<&str>, u64> = BTreeMap::new();
+        for item in self.items.iter() {
+            *totals.entry(item.fee.currency()).or_default() += item.amount();
+        }
+
+        totals.into_iter().collect()
+    }
+}
+
+impl FeeItem<'_> {
+    /// The value of the fee times the number of times it is charged.
+    pub fn amount(&self) -> u64 {
+        self.fee.value() * self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::model::{Athlete, Gender, Relay, Session, Stroke, SwimStyle};
+
+    fn meet() -> Meet {
+        let mut individual = Event::new(1, 1, SwimStyle::new(1, 50, 1, Stroke::Free));
+        let mut fee = Fee::untyped(800);
+        fee.with_currency("CAD".into());
+        individual.with_fee(fee);
+
+        let mut relay = Event::new(2, 2, SwimStyle::new(2, 50, 4, Stroke::Free));
+        let mut fee = Fee::untyped(1600);
+        fee.with_currency("CAD".into());
+        relay.with_fee(fee);
+
+        let mut meet = Meet::new(
+            "Meet".into(),
+            "CAN".into(),
+            "Montreal".into(),
+            vec![Session::new(
+                1,
+                NaiveDate::from_ymd_opt(2023, 2, 11).unwrap(),
+                vec![
+                    individual,
+                    relay,
+                    Event::new(3, 3, SwimStyle::new(3, 100, 1, Stroke::Back)),
+                ],
+            )],
+        );
+        for (r#type, value) in [
+            (FeeType::Club, 5000),
+            (FeeType::Athlete, 1000),
+            (FeeType::Relay, 500),
+            (FeeType::LateEntryIndividual, 300),
+            (FeeType::Other("CAN.TRANSFER".into()), 2000),
+        ] {
+            let mut fee = Fee::new(r#type, value);
+            fee.with_currency("CAD".into());
+            meet.add_fee(fee);
+        }
+
+        meet
+    }
+
+    fn club() -> Club {
+        let mut club = Club::new(1, "Club".into());
+        for id in 1..=3 {
+            let mut athlete = Athlete::new(
+                id,
+                "Jane".into(),
+                "Doe".into(),
+                Gender::Female,
+                NaiveDate::from_ymd_opt(2012, 6, 1).unwrap().into(),
+            );
+            for event_id in 1..id {
+                athlete.add_entry(Entry::new(event_id * 2 - 1));
+            }
+            club.add_athlete(athlete);
+        }
+
+        let mut relay = Relay::new(1, Gender::Female);
+        relay.add_entry(Entry::new(2));
+        club.add_relay(relay);
+
+        club
+    }
+
+    #[test]
+    fn club_fees() {
+        let meet = meet();
+        let statement = FeeCalculator::new(&meet).club(&club());
+
+        let items: Vec<_> = statement
+            .items
+            .iter()
+            .map(|item| (item.fee.r#type().cloned(), item.event_id, item.count))
+            .collect();
+        assert_eq!(
+            vec![
+                (Some(FeeType::Club), None, 1),
+                (Some(FeeType::Athlete), None, 2),
+                (Some(FeeType::Relay), None, 1),
+                (None, Some(1), 2),
+                (None, Some(2), 1),
+            ],
+            items
+        );
+        assert_eq!(vec![(Some("CAD"), 10_700)], statement.totals());
+    }
+
+    #[test]
+    fn late_club_fees() {
+        let meet = meet();
+        let statement = FeeCalculator::new(&meet)
+            .with_late_entries(true)
+            .club(&club());
+        assert_eq!(vec![(Some("CAD"), 11_600)], statement.totals());
+
+        let statement = FeeCalculator::new(&meet).club(&Club::new(2, "Club".into()));
+        assert!(statement.is_empty());
+        assert!(statement.totals().is_empty());
+    }
+}
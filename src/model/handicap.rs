@@ -0,0 +1,254 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+use crate::serialization::preserve::Preserve;
+
+use super::{extra::Extra, stroke::Stroke};
+
+/// The sport classes of a para swimmer, one per group of strokes.
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[serde(rename = "HANDICAP")]
+pub struct Handicap {
+    /// The SB class, for breaststroke.
+    pub breast: Option<SportClass>,
+
+    /// The exception codes of the athlete, such as the starts or finishes they are exempted of.
+    pub exception: Option<String>,
+
+    /// The S class, for freestyle, backstroke and butterfly.
+    pub free: Option<SportClass>,
+
+    /// The SM class, for individual medley.
+    pub medley: Option<SportClass>,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+/// A sport class, `0` standing for athletes without handicap.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum SportClass {
+    /// A class from 0 to 15.
+    Class(u8),
+
+    /// The German class for amputees, written `GER.AB`.
+    GerAb,
+
+    /// The German class for athletes of short stature, written `GER.GB`.
+    GerGb,
+}
+
+/// A list of sport classes, written comma separated in documents.
+#[derive(PartialEq, Default, Debug, Clone)]
+pub struct SportClasses(pub Vec<SportClass>);
+
+#[derive(Error, PartialEq, Debug, Clone)]
+#[error("Invalid sport class {0}, expected 0 to 15, GER.AB or GER.GB.")]
+pub struct ParseSportClassError(pub String);
+
+impl Handicap {
+    /// The class of the athlete for races of `stroke`.
+    pub fn class_for(&self, stroke: &Stroke) -> Option<SportClass> {
+        match stroke {
+            Stroke::Breast => self.breast,
+            Stroke::Medley | Stroke::Imrelay => self.medley,
+            _ => self.free,
+        }
+    }
+
+    /// The class of the athlete for races of `stroke` as written in results, such as `S9`,
+    /// `SB8` or `SM9`.
+    pub fn code_for(&self, stroke: &Stroke) -> Option<String> {
+        let class = self.class_for(stroke)?;
+        let prefix = match stroke {
+            Stroke::Breast => "SB",
+            Stroke::Medley | Stroke::Imrelay => "SM",
+            _ => "S",
+        };
+
+        Some(match class {
+            SportClass::Class(class) => format!("{prefix}{class}"),
+            class => class.to_string(),
+        })
+    }
+}
+
+impl SportClasses {
+    pub fn contains(&self, class: &SportClass) -> bool {
+        self.0.contains(class)
+    }
+
+    /// Whether an athlete of `class` can swim under this restriction, an empty list restricting
+    /// nothing and athletes without class being refused otherwise.
+    pub fn admits(&self, class: Option<SportClass>) -> bool {
+        self.0.is_empty() || class.is_some_and(|class| self.contains(&class))
+    }
+}
+
+impl Display for SportClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SportClass::Class(class) => write!(f, "{class}"),
+            SportClass::GerAb => f.write_str("GER.AB"),
+            SportClass::GerGb => f.write_str("GER.GB"),
+        }
+    }
+}
+
+impl FromStr for SportClass {
+    type Err = ParseSportClassError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "GER.AB" => Ok(SportClass::GerAb),
+            "GER.GB" => Ok(SportClass::GerGb),
+            class => class
+                .parse()
+                .ok()
+                .filter(|class| *class <= 15)
+                .map(SportClass::Class)
+                .ok_or_else(|| ParseSportClassError(s.to_string())),
+        }
+    }
+}
+
+impl Display for SportClasses {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, class) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{class}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for SportClasses {
+    type Err = ParseSportClassError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .filter(|class| !class.trim().is_empty())
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map(SportClasses)
+    }
+}
+
+impl Serialize for SportClass {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SportClass {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for SportClasses {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SportClasses {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl Preserve for Handicap {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fast_xml::{de, se};
+
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(Ok(SportClass::Class(0)), "0".parse());
+        assert_eq!(Ok(SportClass::Class(15)), "15".parse());
+        assert_eq!(Ok(SportClass::GerAb), "GER.AB".parse());
+        assert_eq!(Ok(SportClass::GerGb), "GER.GB".parse());
+        assert!("16".parse::<SportClass>().is_err());
+        assert!("S9".parse::<SportClass>().is_err());
+
+        assert_eq!(
+            Ok(SportClasses(vec![
+                SportClass::Class(8),
+                SportClass::Class(9),
+                SportClass::GerAb
+            ])),
+            "8, 9,GER.AB".parse()
+        );
+    }
+
+    #[test]
+    fn deserialize_handicap() {
+        let result = de::from_str::<Handicap>(r#"<HANDICAP breast="7" free="GER.AB" medley="8"/>"#);
+        assert!(result.is_ok());
+
+        let handicap = result.unwrap();
+        assert_eq!(Some(SportClass::Class(7)), handicap.breast);
+        assert_eq!(Some(SportClass::GerAb), handicap.free);
+        assert_eq!(
+            Some(SportClass::Class(8)),
+            handicap.class_for(&Stroke::Medley)
+        );
+        assert_eq!(Some(SportClass::GerAb), handicap.class_for(&Stroke::Back));
+
+        assert!(de::from_str::<Handicap>(r#"<HANDICAP free="21"/>"#).is_err());
+    }
+
+    #[test]
+    fn codes() {
+        let handicap = Handicap {
+            breast: Some(SportClass::Class(8)),
+            free: Some(SportClass::Class(9)),
+            medley: Some(SportClass::GerGb),
+            ..Default::default()
+        };
+
+        assert_eq!(Some("S9".into()), handicap.code_for(&Stroke::Fly));
+        assert_eq!(Some("SB8".into()), handicap.code_for(&Stroke::Breast));
+        assert_eq!(Some("GER.GB".into()), handicap.code_for(&Stroke::Medley));
+        assert_eq!(
+            r#"<HANDICAP breast="8" free="9" medley="GER.GB"/>"#,
+            se::to_string(&handicap).unwrap()
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use chrono::NaiveTime;
+use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
+
+use crate::serialization::{preserve::Preserve, serde_time};
+
+use super::extra::Extra;
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[serde(rename = "HEAT")]
+pub struct Heat {
+    #[serde(rename = "heatid")]
+    pub id: u32,
+
+    pub number: u32,
+
+    #[serde(default, with = "crate::serialization::serde_number")]
+    pub order: Option<u32>,
+
+    #[serde(rename = "daytime", default, with = "serde_time::optional")]
+    pub day_time: Option<NaiveTime>,
+
+    pub status: Option<HeatStatus>,
+
+    pub r#final: Option<Final>,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+#[derive(Serialize, Deserialize, IntoStaticStr, PartialEq, Debug, Clone)]
+#[serde(rename_all = "UPPERCASE", into = "&str")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum HeatStatus {
+    Scheduled,
+    Seeded,
+    Inofficial,
+    Official,
+}
+
+#[derive(Serialize, Deserialize, IntoStaticStr, PartialEq, Debug, Clone)]
+#[serde(into = "&str")]
+pub enum Final {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl Heat {
+    pub fn new(id: u32, number: u32) -> Self {
+        Self {
+            id,
+            number,
+            ..Default::default()
+        }
+    }
+}
+
+impl Preserve for Heat {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Timelike;
+    use fast_xml::{de, se};
+
+    use super::*;
+
+    #[test]
+    fn deserialize_empty() {
+        let result = de::from_str::<Heat>(r#"<HEAT/>"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deserialize_basic() {
+        let result = de::from_str::<Heat>(
+            r#"<HEAT heatid="1001" number="2" order="2" daytime="09:42" status="SEEDED" final="A"/>"#,
+        );
+        assert!(result.is_ok());
+
+        let heat = result.unwrap();
+        assert_eq!(1001, heat.id);
+        assert_eq!(2, heat.number);
+        assert_eq!(Some(2), heat.order);
+        assert_eq!(42, heat.day_time.unwrap().minute());
+        assert_eq!(Some(HeatStatus::Seeded), heat.status);
+        assert_eq!(Some(Final::A), heat.r#final);
+    }
+
+    #[test]
+    fn serialize() {
+        let heat = Heat {
+            id: 1001,
+            number: 1,
+            status: Some(HeatStatus::Official),
+            ..Default::default()
+        };
+
+        let result = se::to_string(&heat);
+        assert!(result.is_ok());
+        assert_eq!(
+            r#"<HEAT heatid="1001" number="1" status="OFFICIAL"/>"#,
+            result.unwrap()
+        );
+    }
+}
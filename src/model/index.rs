@@ -0,0 +1,261 @@
+use std::collections::{BTreeMap, HashMap};
+
+use super::{
+    age_group::AgeGroup, athlete::Athlete, club::Club, entry::Entry, event::Event,
+    handicap::SportClass, lenex::Lenex, relay::Relay, result::Result, session::Session,
+};
+
+/// The individual results of an event swum by athletes of the same sport class.
+pub type SportClassResults<'a> = (Option<SportClass>, Vec<(&'a Club, &'a Athlete, &'a Result)>);
+
+/// Lookup tables resolving the identifiers of a [`Lenex`] document to the elements they refer to.
+///
+/// Identifiers are expected to be unique within the document. When they are not, the first
+/// element in document order wins. [`Lenex::validate`] reports the event, age group, club and
+/// athlete ids colliding within a meet, but not those shared by two meets of the document.
+#[derive(Debug, Default)]
+pub struct LenexIndex<'a> {
+    events: HashMap<u32, (&'a Session, &'a Event)>,
+    athletes: HashMap<u32, (&'a Club, &'a Athlete)>,
+    clubs: HashMap<u32, &'a Club>,
+    age_groups: HashMap<u32, (&'a Event, &'a AgeGroup)>,
+    entries: HashMap<u32, Vec<(&'a Club, &'a Athlete, &'a Entry)>>,
+    relay_entries: HashMap<u32, Vec<(&'a Club, &'a Relay, &'a Entry)>>,
+    results: HashMap<u32, Vec<(&'a Club, &'a Athlete, &'a Result)>>,
+}
+
+impl<'a> LenexIndex<'a> {
+    pub fn new(lenex: &'a Lenex) -> Self {
+        let mut index = Self::default();
+
+        for meet in lenex.meets.iter() {
+            for session in meet.sessions() {
+                for event in session.events() {
+                    index.events.entry(event.id).or_insert((session, event));
+
+                    for age_group in event.age_groups() {
+                        index
+                            .age_groups
+                            .entry(age_group.id)
+                            .or_insert((event, age_group));
+                    }
+                }
+            }
+
+            for club in meet.clubs() {
+                index.clubs.entry(club.id()).or_insert(club);
+
+                for athlete in club.athletes() {
+                    index
+                        .athletes
+                        .entry(athlete.id())
+                        .or_insert((club, athlete));
+
+                    for entry in athlete.entries() {
+                        index
+                            .entries
+                            .entry(entry.event_id())
+                            .or_default()
+                            .push((club, athlete, entry));
+                    }
+
+                    for result in athlete.results() {
+                        index
+                            .results
+                            .entry(result.event_id())
+                            .or_default()
+                            .push((club, athlete, result));
+                    }
+                }
+
+                for relay in club.relays() {
+                    for entry in relay.entries().iter() {
+                        index
+                            .relay_entries
+                            .entry(entry.event_id())
+                            .or_default()
+                            .push((club, relay, entry));
+                    }
+                }
+            }
+        }
+
+        index
+    }
+
+    pub fn event(&self, id: u32) -> Option<(&'a Session, &'a Event)> {
+        self.events.get(&id).copied()
+    }
+
+    pub fn athlete(&self, id: u32) -> Option<(&'a Club, &'a Athlete)> {
+        self.athletes.get(&id).copied()
+    }
+
+    pub fn club(&self, id: u32) -> Option<&'a Club> {
+        self.clubs.get(&id).copied()
+    }
+
+    pub fn age_group(&self, id: u32) -> Option<(&'a Event, &'a AgeGroup)> {
+        self.age_groups.get(&id).copied()
+    }
+
+    /// Individual entries of the event, in document order.
+    pub fn entries_for_event(
+        &self,
+        event_id: u32,
+    ) -> impl Iterator<Item = (&'a Club, &'a Athlete, &'a Entry)> + '_ {
+        self.entries.get(&event_id).into_iter().flatten().copied()
+    }
+
+    /// Relay entries of the event, in document order.
+    pub fn relay_entries_for_event(
+        &self,
+        event_id: u32,
+    ) -> impl Iterator<Item = (&'a Club, &'a Relay, &'a Entry)> + '_ {
+        self.relay_entries
+            .get(&event_id)
+            .into_iter()
+            .flatten()
+            .copied()
+    }
+
+    /// Individual results of the event, in document order.
+    pub fn results_for_event(
+        &self,
+        event_id: u32,
+    ) -> impl Iterator<Item = (&'a Club, &'a Athlete, &'a Result)> + '_ {
+        self.results.get(&event_id).into_iter().flatten().copied()
+    }
+
+    /// Individual results of the event grouped by the sport class of the athletes for its
+    /// stroke, classes in ascending order and athletes without class last.
+    ///
+    /// Each group is ranked from the fastest time, results without time or with a status such as
+    /// a disqualification coming last.
+    pub fn results_by_sport_class(&self, event_id: u32) -> Vec<SportClassResults<'a>> {
+        let event = self.event(event_id).map(|(_, event)| event);
+
+        let mut groups: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for (club, athlete, result) in self.results_for_event(event_id) {
+            let class = event.and_then(|event| event.sport_class(athlete));
+            groups
+                .entry((class.is_none(), class))
+                .or_default()
+                .push((club, athlete, result));
+        }
+
+        groups
+            .into_iter()
+            .map(|((_, class), mut results)| {
+                results.sort_by_key(|(_, _, result)| {
+                    (
+                        result.status().is_some(),
+                        result.swim_time().is_none(),
+                        result.swim_time(),
+                    )
+                });
+
+                (class, results)
+            })
+            .collect()
+    }
+}
+
+impl Lenex {
+    pub fn index(&self) -> LenexIndex<'_> {
+        LenexIndex::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lenex() -> Lenex {
+        Lenex::try_from(
+            r#"<LENEX version="3.0"><CONSTRUCTOR name="lenex-rs" registration="lenex-rs" version="0.1.0"><CONTACT name="lenex-rs" country="CA" email="lenex@example.com" internet="https://github.com/francisbr/lenex-rs"/></CONSTRUCTOR><MEETS><MEET name="Meet" city="Montreal" nation="CAN"><SESSIONS><SESSION date="2023-02-11" number="1"><EVENTS><EVENT eventid="10" number="1"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/><AGEGROUPS><AGEGROUP agegroupid="100"/></AGEGROUPS></EVENT><EVENT eventid="20" number="2"><SWIMSTYLE swimstyleid="2" distance="50" relaycount="4" stroke="FREE"/></EVENT></EVENTS></SESSION></SESSIONS><CLUBS><CLUB clubid="1" name="Club A"><ATHLETES><ATHLETE athleteid="1" firstname="Jane" lastname="Doe" gender="F" birthdate="2010-01-01"><ENTRIES><ENTRY eventid="10"/></ENTRIES><RESULTS><RESULT resultid="1" eventid="10"/></RESULTS></ATHLETE></ATHLETES><RELAYS><RELAY number="1" gender="F"><ENTRIES><ENTRY eventid="20"/></ENTRIES></RELAY></RELAYS></CLUB><CLUB clubid="2" name="Club B"><ATHLETES><ATHLETE athleteid="2" firstname="John" lastname="Doe" gender="M" birthdate="2011-01-01"><ENTRIES><ENTRY eventid="10"/></ENTRIES></ATHLETE></ATHLETES></CLUB></CLUBS></MEET></MEETS></LENEX>"#.to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn lookup_ids() {
+        let lenex = lenex();
+        let index = lenex.index();
+
+        let (session, event) = index.event(20).unwrap();
+        assert_eq!(1, session.number());
+        assert_eq!(2, event.number());
+
+        let (club, athlete) = index.athlete(2).unwrap();
+        assert_eq!("Club B", club.name());
+        assert_eq!("John", athlete.first_name());
+
+        assert_eq!("Club A", index.club(1).unwrap().name());
+
+        let (event, age_group) = index.age_group(100).unwrap();
+        assert_eq!(10, event.id);
+        assert_eq!(100, age_group.id);
+
+        assert!(index.event(30).is_none());
+        assert!(index.athlete(3).is_none());
+        assert!(index.club(3).is_none());
+        assert!(index.age_group(1).is_none());
+    }
+
+    #[test]
+    fn entries_for_event() {
+        let lenex = lenex();
+        let index = lenex.index();
+
+        let entries: Vec<_> = index
+            .entries_for_event(10)
+            .map(|(club, athlete, _)| (club.id(), athlete.id()))
+            .collect();
+        assert_eq!(vec![(1, 1), (2, 2)], entries);
+
+        let relays: Vec<_> = index
+            .relay_entries_for_event(20)
+            .map(|(club, relay, _)| (club.id(), relay.number()))
+            .collect();
+        assert_eq!(vec![(1, Some(1))], relays);
+
+        assert_eq!(1, index.results_for_event(10).count());
+        assert_eq!(0, index.entries_for_event(20).count());
+    }
+
+    #[test]
+    fn results_by_sport_class() {
+        let lenex = Lenex::try_from(
+            r#"<LENEX version="3.0"><CONSTRUCTOR name="lenex-rs" registration="lenex-rs" version="0.1.0"><CONTACT/></CONSTRUCTOR><MEETS><MEET name="Meet" city="Montreal" nation="CAN"><SESSIONS><SESSION date="2023-02-11" number="1"><EVENTS><EVENT eventid="10" number="1" handicap="8,9"><SWIMSTYLE swimstyleid="1" distance="100" relaycount="1" stroke="BREAST"/></EVENT></EVENTS></SESSION></SESSIONS><CLUBS><CLUB clubid="1" name="Club A"><ATHLETES><ATHLETE athleteid="1" firstname="A" lastname="A" gender="F"><HANDICAP breast="9" free="10"/><RESULTS><RESULT resultid="1" eventid="10" swimtime="00:01:45.00"/></RESULTS></ATHLETE><ATHLETE athleteid="2" firstname="B" lastname="B" gender="F"><HANDICAP breast="8"/><RESULTS><RESULT resultid="2" eventid="10" swimtime="00:01:50.00"/></RESULTS></ATHLETE><ATHLETE athleteid="3" firstname="C" lastname="C" gender="F"><HANDICAP breast="9"/><RESULTS><RESULT resultid="3" eventid="10" swimtime="00:01:40.00" status="DSQ"/></RESULTS></ATHLETE><ATHLETE athleteid="4" firstname="D" lastname="D" gender="F"><HANDICAP breast="9"/><RESULTS><RESULT resultid="4" eventid="10" swimtime="00:01:41.00"/></RESULTS></ATHLETE><ATHLETE athleteid="5" firstname="E" lastname="E" gender="F"><RESULTS><RESULT resultid="5" eventid="10" swimtime="00:01:30.00"/></RESULTS></ATHLETE></ATHLETES></CLUB></CLUBS></MEET></MEETS></LENEX>"#.to_string(),
+        )
+        .unwrap();
+        let index = lenex.index();
+
+        let groups: Vec<_> = index
+            .results_by_sport_class(10)
+            .into_iter()
+            .map(|(class, results)| {
+                (
+                    class,
+                    results
+                        .iter()
+                        .map(|(_, athlete, _)| athlete.id())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                (Some(SportClass::Class(8)), vec![2]),
+                (Some(SportClass::Class(9)), vec![4, 1, 3]),
+                (None, vec![5]),
+            ],
+            groups
+        );
+
+        let (_, event) = index.event(10).unwrap();
+        assert!(event.accepts_handicap(index.athlete(1).unwrap().1));
+        assert!(!event.accepts_handicap(index.athlete(5).unwrap().1));
+    }
+}
@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
+
+use crate::serialization::preserve::Preserve;
+
+use super::{extra::Extra, official::Official};
+
+/// The assignment of an official to a session.
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[serde(rename = "JUDGE")]
+pub struct Judge {
+    #[serde(rename = "officialid")]
+    pub official_id: u32,
+
+    pub role: Option<JudgeRole>,
+
+    /// Tells apart officials holding the same role, such as the lane of a timekeeper.
+    #[serde(default, with = "crate::serialization::serde_number")]
+    pub number: Option<u32>,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+/// The role of an official in a session, ordered from the meet director down.
+#[derive(
+    Serialize, Deserialize, IntoStaticStr, PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy,
+)]
+#[serde(into = "&str")]
+pub enum JudgeRole {
+    #[serde(rename(deserialize = "MDR"))]
+    #[strum(serialize = "MDR")]
+    MeetDirector,
+
+    #[serde(rename(deserialize = "TDG"))]
+    #[strum(serialize = "TDG")]
+    TechnicalDelegate,
+
+    #[serde(rename(deserialize = "REF"))]
+    #[strum(serialize = "REF")]
+    Referee,
+
+    #[serde(rename(deserialize = "STA"))]
+    #[strum(serialize = "STA")]
+    Starter,
+
+    #[serde(rename(deserialize = "ANN"))]
+    #[strum(serialize = "ANN")]
+    Announcer,
+
+    #[serde(rename(deserialize = "JOS"))]
+    #[strum(serialize = "JOS")]
+    StrokeJudge,
+
+    #[serde(rename(deserialize = "CTIK"))]
+    #[strum(serialize = "CTIK")]
+    ChiefTimekeeper,
+
+    #[serde(rename(deserialize = "TIK"))]
+    #[strum(serialize = "TIK")]
+    Timekeeper,
+
+    #[serde(rename(deserialize = "CFIN"))]
+    #[strum(serialize = "CFIN")]
+    ChiefFinishJudge,
+
+    #[serde(rename(deserialize = "FIN"))]
+    #[strum(serialize = "FIN")]
+    FinishJudge,
+
+    #[serde(rename(deserialize = "CIOT"))]
+    #[strum(serialize = "CIOT")]
+    ChiefInspectorOfTurns,
+
+    #[serde(rename(deserialize = "IOT"))]
+    #[strum(serialize = "IOT")]
+    InspectorOfTurns,
+
+    #[serde(rename(deserialize = "FSR"))]
+    #[strum(serialize = "FSR")]
+    FalseStartRope,
+
+    #[serde(rename(deserialize = "COC"))]
+    #[strum(serialize = "COC")]
+    ClerkOfCourse,
+
+    #[serde(rename(deserialize = "CREC"))]
+    #[strum(serialize = "CREC")]
+    ChiefRecorder,
+
+    #[serde(rename(deserialize = "REC"))]
+    #[strum(serialize = "REC")]
+    Recorder,
+
+    #[serde(rename(deserialize = "CRS"))]
+    #[strum(serialize = "CRS")]
+    ControlRoomSupervisor,
+
+    #[serde(rename(deserialize = "CR"))]
+    #[strum(serialize = "CR")]
+    ControlRoom,
+
+    #[serde(rename(deserialize = "MED"))]
+    #[strum(serialize = "MED")]
+    Medical,
+
+    #[serde(rename(deserialize = "OTH"))]
+    #[strum(serialize = "OTH")]
+    Other,
+}
+
+/// One official of a session roster, as returned by [`Meet::roster`](super::Meet::roster).
+///
+/// The official is missing when the meet does not list the one the judge refers to.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct RosterEntry<'a> {
+    pub judge: &'a Judge,
+    pub official: Option<&'a Official>,
+}
+
+impl Judge {
+    pub fn new(official_id: u32, role: JudgeRole) -> Self {
+        Self {
+            official_id,
+            role: Some(role),
+            ..Default::default()
+        }
+    }
+}
+
+impl Preserve for Judge {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fast_xml::{de, se};
+
+    use super::*;
+
+    #[test]
+    fn deserialize_basic() {
+        let result = de::from_str::<Judge>(r#"<JUDGE officialid="3" role="TIK" number="4"/>"#);
+        assert!(result.is_ok());
+
+        let judge = result.unwrap();
+        assert_eq!(3, judge.official_id);
+        assert_eq!(Some(JudgeRole::Timekeeper), judge.role);
+        assert_eq!(Some(4), judge.number);
+    }
+
+    #[test]
+    fn serialize_built() {
+        let judge = Judge::new(1, JudgeRole::ChiefTimekeeper);
+
+        let result = se::to_string(&judge);
+        assert!(result.is_ok());
+        assert_eq!(r#"<JUDGE officialid="1" role="CTIK"/>"#, result.unwrap());
+    }
+}
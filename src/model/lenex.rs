@@ -1,11 +1,35 @@
+use encoding_rs::Encoding;
 use fast_xml::{de, se, DeError};
 use serde::{Deserialize, Serialize, Serializer};
 
-use crate::collection::Collection;
+use crate::{
+    collection::Collection,
+    error::Error,
+    serialization::{
+        encoding,
+        preserve::{
+            preserve, preserve_child, preserve_collection, restore, restore_child,
+            restore_collection, Node, Preserve,
+        },
+    },
+};
 
-use super::meet::Meet;
+use super::{
+    contact::Contact,
+    course::Course,
+    entry::Entry,
+    event::Event,
+    extra::Extra,
+    gender::Gender,
+    meet::Meet,
+    record::Record,
+    record_list::RecordList,
+    swimstyle::SwimStyle,
+    time_standard_list::{TimeStandardList, TimeStandardListType},
+    time_standard_ref::TimeStandardCheck,
+};
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename = "LENEX")]
 pub struct Lenex {
     #[serde(serialize_with = "serialize_version")]
@@ -14,8 +38,24 @@ pub struct Lenex {
     #[serde(rename = "CONSTRUCTOR")]
     pub constructor: Constructor,
 
-    #[serde(rename = "MEETS")]
-    meets: Collection<Meet>,
+    #[serde(rename = "MEETS", default)]
+    pub meets: Collection<Meet>,
+
+    #[serde(rename = "RECORDLISTS", default, skip_serializing_if = "Vec::is_empty")]
+    pub record_lists: Collection<RecordList>,
+
+    #[serde(
+        rename = "TIMESTANDARDLISTS",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub time_standard_lists: Collection<TimeStandardList>,
+
+    #[serde(skip)]
+    lossless: bool,
+
+    #[serde(skip)]
+    pub extra: Extra,
 }
 
 impl Lenex {
@@ -26,22 +66,148 @@ impl Lenex {
                 name: "lenex-rs".into(),
                 registration: "".into(),
                 contact: Contact {
-                    name: "Francis Boulet-Rouleau".into(),
-                    country: "CA".into(),
-                    email: "francisbouletrouleau@gmail.com".into(),
-                    internet: "https://github.com/francisbr/lenex-rs".into(),
+                    name: Some("Francis Boulet-Rouleau".into()),
+                    country: Some("CA".into()),
+                    email: Some("francisbouletrouleau@gmail.com".into()),
+                    internet: Some("https://github.com/francisbr/lenex-rs".into()),
+                    ..Default::default()
                 },
                 version: env!("CARGO_PKG_VERSION").into(),
+                extra: Extra::default(),
             },
             meets: Vec::new().into(),
+            record_lists: Vec::new().into(),
+            time_standard_lists: Vec::new().into(),
+            lossless: false,
+            extra: Extra::default(),
         }
     }
 
+    /// Whether the document was read by a lossless reader, in which case the extras of every
+    /// element are written back by [`Lenex::xml`].
+    pub fn lossless(&self) -> bool {
+        self.lossless
+    }
+
+    pub fn time_standard_list(&self, id: u32) -> Option<&TimeStandardList> {
+        self.time_standard_lists.iter().find(|list| list.id == id)
+    }
+
+    /// Checks the entry time of `entry` against the time standards referenced by `event` that
+    /// apply to a swimmer of `age` in a meet swum in `course`.
+    ///
+    /// References to lists of another gender than the event, of another course, for another age
+    /// group, to default lists or to lists without a time for the swim style of the event are
+    /// skipped. See [`TimeStandardList::covers_age`] for how `age` selects lists.
+    pub fn check_entry<'a>(
+        &'a self,
+        entry: &Entry,
+        event: &'a Event,
+        course: &Course,
+        age: Option<u16>,
+    ) -> Vec<TimeStandardCheck<'a>> {
+        event
+            .time_standard_refs()
+            .iter()
+            .filter_map(|reference| {
+                let list = self.time_standard_list(reference.time_standard_list_id)?;
+                if list.r#type == Some(TimeStandardListType::Default)
+                    || !event.accepts_gender(&list.gender)
+                    || &list.course != course
+                    || !list.covers_age(age)
+                {
+                    return None;
+                }
+
+                let time_standard = list.time_standard_for(event.swim_style())?;
+                let met = list.is_met(entry.entry_time(), time_standard.swim_time?);
+
+                Some(TimeStandardCheck {
+                    reference,
+                    list,
+                    time_standard,
+                    status: reference.status(met),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the records of every record list matching the course, gender and age.
+    ///
+    /// See [`RecordList::covers_age`] for how `age` selects record lists.
+    pub fn records_for(
+        &self,
+        swim_style: &SwimStyle,
+        course: &Course,
+        gender: &Gender,
+        age: Option<u16>,
+    ) -> Vec<(&RecordList, &Record)> {
+        self.record_lists
+            .iter()
+            .filter(|list| &list.course == course && &list.gender == gender && list.covers_age(age))
+            .flat_map(|list| list.records.iter().map(move |record| (list, record)))
+            .filter(|(_, record)| record.swim_style.is_same_race(swim_style))
+            .collect()
+    }
+
+    /// Returns the fastest record matching the swim style, course, gender and age.
+    pub fn record_for(
+        &self,
+        swim_style: &SwimStyle,
+        course: &Course,
+        gender: &Gender,
+        age: Option<u16>,
+    ) -> Option<&Record> {
+        self.records_for(swim_style, course, gender, age)
+            .into_iter()
+            .filter_map(|(_, record)| record.swim_time.map(|time| (time, record)))
+            .min_by_key(|(time, _)| *time)
+            .map(|(_, record)| record)
+    }
+
     pub fn xml(&self) -> Result<String, DeError> {
-        Ok(format!(
-            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>{}",
-            se::to_string(&self)?
-        ))
+        Ok(Self::declaration("UTF-8") + &self.body()?)
+    }
+
+    /// Serializes the document in the encoding named by `label`, such as `ISO-8859-1`, escaping
+    /// the characters it cannot represent. The XML declaration names the encoding as given.
+    ///
+    /// Encodings that cannot be written, such as UTF-16, fall back to UTF-8. ISO-8859-1 is written
+    /// as Windows-1252, so documents holding characters only Windows-1252 has, such as `€`, are
+    /// declared Windows-1252.
+    pub fn xml_with_encoding(&self, label: &str) -> crate::Result<Vec<u8>> {
+        let encoding = Encoding::for_label(label.as_bytes())
+            .ok_or_else(|| Error::UnsupportedEncoding(label.to_string()))?;
+        let body = self.body().map_err(Error::Serialize)?;
+
+        let xml = Self::declaration(encoding::output_label(label, encoding, &body)) + &body;
+        let (bytes, _, _) = encoding.output_encoding().encode(&xml);
+
+        Ok(bytes.into_owned())
+    }
+
+    /// Keeps the unknown attributes and elements of `xml`, the document this one was read from.
+    pub(crate) fn preserve_extras(&mut self, xml: &str) -> Result<(), DeError> {
+        preserve(self, &Node::parse(xml)?);
+        self.lossless = true;
+
+        Ok(())
+    }
+
+    fn body(&self) -> Result<String, DeError> {
+        let xml = se::to_string(&self)?;
+        if !self.lossless {
+            return Ok(xml);
+        }
+
+        let mut node = Node::parse(&xml)?;
+        restore(self, &mut node);
+
+        Ok(node.to_string())
+    }
+
+    fn declaration(label: &str) -> String {
+        format!("<?xml version=\"1.0\" encoding=\"{label}\"?>")
     }
 }
 
@@ -51,6 +217,17 @@ impl Default for Lenex {
     }
 }
 
+/// Documents compare by their content, however they were read.
+impl PartialEq for Lenex {
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version
+            && self.constructor == other.constructor
+            && self.meets == other.meets
+            && self.record_lists == other.record_lists
+            && self.time_standard_lists == other.time_standard_lists
+    }
+}
+
 impl TryFrom<String> for Lenex {
     type Error = DeError;
 
@@ -67,7 +244,7 @@ impl TryInto<String> for Lenex {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct Constructor {
     pub name: String,
     pub registration: String,
@@ -75,6 +252,9 @@ pub struct Constructor {
 
     #[serde(rename = "CONTACT")]
     pub contact: Contact,
+
+    #[serde(skip)]
+    pub extra: Extra,
 }
 
 fn serialize_version<S>(x: &f32, s: S) -> Result<S::Ok, S::Error>
@@ -84,10 +264,139 @@ where
     s.serialize_str(&format!("{x:.1}"))
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
-pub struct Contact {
-    pub name: String,
-    pub country: String,
-    pub email: String,
-    pub internet: String,
+impl Preserve for Lenex {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_child(Some(&mut self.constructor), node, "CONSTRUCTOR");
+        preserve_collection(&mut self.meets, node, "MEETS");
+        preserve_collection(&mut self.record_lists, node, "RECORDLISTS");
+        preserve_collection(&mut self.time_standard_lists, node, "TIMESTANDARDLISTS");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_child(Some(&self.constructor), node, "CONSTRUCTOR");
+        restore_collection(&self.meets, node, "MEETS");
+        restore_collection(&self.record_lists, node, "RECORDLISTS");
+        restore_collection(&self.time_standard_lists, node, "TIMESTANDARDLISTS");
+    }
+}
+
+impl Preserve for Constructor {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_child(Some(&mut self.contact), node, "CONTACT");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_child(Some(&self.contact), node, "CONTACT");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{SwimTime, TimeStandardStatus};
+
+    use super::*;
+
+    #[test]
+    fn round_trip_results() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?><LENEX version="3.0"><CONSTRUCTOR name="lenex-rs" registration="lenex-rs" version="0.1.0"><CONTACT name="lenex-rs" country="CA" email="lenex@example.com" internet="https://github.com/francisbr/lenex-rs"/></CONSTRUCTOR><MEETS><MEET name="Meet" city="Montreal" nation="CAN"><SESSIONS><SESSION date="2023-02-11" number="1"><EVENTS/></SESSION></SESSIONS><CLUBS><CLUB clubid="1" name="Club"><ATHLETES><ATHLETE athleteid="2" firstname="Jane" lastname="Doe" gender="F" birthdate="2010-01-01"><RESULTS><RESULT resultid="1" eventid="3" swimtime="00:00:31.20" lane="4"><SPLITS><SPLIT distance="25" swimtime="00:00:15.00"/></SPLITS></RESULT><RESULT resultid="2" eventid="5" status="DNS"/></RESULTS></ATHLETE></ATHLETES></CLUB></CLUBS></MEET></MEETS></LENEX>"#;
+
+        let lenex = Lenex::try_from(xml.to_string());
+        assert!(lenex.is_ok());
+        let lenex = lenex.unwrap();
+
+        let output = lenex.xml();
+        assert!(output.is_ok());
+
+        let reparsed = Lenex::try_from(output.unwrap());
+        assert!(reparsed.is_ok());
+        assert_eq!(lenex, reparsed.unwrap());
+    }
+
+    #[test]
+    fn check_entry() {
+        let xml = r#"<LENEX version="3.0"><CONSTRUCTOR name="lenex-rs" registration="lenex-rs" version="0.1.0"><CONTACT name="lenex-rs" country="CA" email="lenex@example.com" internet="https://github.com/francisbr/lenex-rs"/></CONSTRUCTOR><MEETS><MEET name="Meet" city="Montreal" nation="CAN"><SESSIONS><SESSION date="2023-02-11" number="1"><EVENTS><EVENT eventid="1" number="1" gender="F"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/><TIMESTANDARDREFS><TIMESTANDARDREF timestandardlistid="1"/><TIMESTANDARDREF timestandardlistid="2" marker="F"/><TIMESTANDARDREF timestandardlistid="3"><FEE value="1000"/></TIMESTANDARDREF><TIMESTANDARDREF timestandardlistid="4"/><TIMESTANDARDREF timestandardlistid="5"/><TIMESTANDARDREF timestandardlistid="6"/><TIMESTANDARDREF timestandardlistid="7"/></TIMESTANDARDREFS></EVENT></EVENTS></SESSION></SESSIONS><CLUBS><CLUB clubid="1" name="Club"><ATHLETES><ATHLETE athleteid="1" firstname="Jane" lastname="Doe" gender="F" birthdate="2010-01-01"><ENTRIES><ENTRY eventid="1" entrytime="00:00:30.00"/></ENTRIES></ATHLETE></ATHLETES></CLUB></CLUBS></MEET></MEETS><TIMESTANDARDLISTS><TIMESTANDARDLIST timestandardlistid="1" course="LCM" gender="F" name="Cut" type="MAXIMUM"><TIMESTANDARDS><TIMESTANDARD swimtime="00:00:31.00"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/></TIMESTANDARD></TIMESTANDARDS></TIMESTANDARDLIST><TIMESTANDARDLIST timestandardlistid="2" course="LCM" gender="F" name="Finals" type="MAXIMUM"><TIMESTANDARDS><TIMESTANDARD swimtime="00:00:29.00"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/></TIMESTANDARD></TIMESTANDARDS></TIMESTANDARDLIST><TIMESTANDARDLIST timestandardlistid="3" course="LCM" gender="F" name="Slowest" type="MINIMUM"><TIMESTANDARDS><TIMESTANDARD swimtime="00:00:35.00"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/></TIMESTANDARD></TIMESTANDARDS></TIMESTANDARDLIST><TIMESTANDARDLIST timestandardlistid="4" course="LCM" gender="M" name="Men" type="MAXIMUM"><TIMESTANDARDS><TIMESTANDARD swimtime="00:00:28.00"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/></TIMESTANDARD></TIMESTANDARDS></TIMESTANDARDLIST><TIMESTANDARDLIST timestandardlistid="5" course="SCM" gender="F" name="Short course" type="MAXIMUM"><TIMESTANDARDS><TIMESTANDARD swimtime="00:00:29.50"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/></TIMESTANDARD></TIMESTANDARDS></TIMESTANDARDLIST><TIMESTANDARDLIST timestandardlistid="6" course="LCM" gender="F" name="11-12" type="MAXIMUM"><AGEGROUP agegroupid="1" agemin="11" agemax="12"/><TIMESTANDARDS><TIMESTANDARD swimtime="00:00:33.00"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/></TIMESTANDARD></TIMESTANDARDS></TIMESTANDARDLIST><TIMESTANDARDLIST timestandardlistid="7" course="LCM" gender="F" name="13-14" type="MAXIMUM"><AGEGROUP agegroupid="2" agemin="13" agemax="14"/><TIMESTANDARDS><TIMESTANDARD swimtime="00:00:29.80"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/></TIMESTANDARD></TIMESTANDARDS></TIMESTANDARDLIST></TIMESTANDARDLISTS></LENEX>"#;
+
+        let lenex = Lenex::try_from(xml.to_string());
+        assert!(lenex.is_ok());
+        let lenex = lenex.unwrap();
+
+        let meet = lenex.meets.first().unwrap();
+        let event = meet.sessions().first().unwrap().events().first().unwrap();
+        let club = meet.clubs().first().unwrap();
+        let entry = club.athletes().first().unwrap().entries().first().unwrap();
+
+        let checks = lenex.check_entry(entry, event, &Course::LCM, Some(13));
+        assert_eq!(4, checks.len());
+
+        assert_eq!(1, checks[0].list.id);
+        assert_eq!(TimeStandardStatus::Met, checks[0].status);
+        assert_eq!(TimeStandardStatus::Unmarked, checks[1].status);
+        assert!(matches!(checks[2].status, TimeStandardStatus::Penalty(_)));
+        assert_eq!(7, checks[3].list.id);
+        assert_eq!(TimeStandardStatus::NotMet, checks[3].status);
+
+        let checks = lenex.check_entry(entry, event, &Course::LCM, None);
+        assert_eq!(3, checks.len());
+        assert!(checks.iter().all(|check| check.status.is_accepted()));
+    }
+
+    #[test]
+    fn record_for() {
+        let xml = r#"<LENEX version="3.0"><CONSTRUCTOR name="lenex-rs" registration="lenex-rs" version="0.1.0"><CONTACT name="lenex-rs" country="CA" email="lenex@example.com" internet="https://github.com/francisbr/lenex-rs"/></CONSTRUCTOR><RECORDLISTS><RECORDLIST course="LCM" gender="M" name="Open"><RECORDS><RECORD swimtime="00:00:22.10"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/></RECORD></RECORDS></RECORDLIST><RECORDLIST course="LCM" gender="M" name="13-14 national"><AGEGROUP agegroupid="1" agemin="13" agemax="14"/><RECORDS><RECORD swimtime="00:00:24.80"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/></RECORD><RECORD swimtime="00:00:27.90"><SWIMSTYLE swimstyleid="2" distance="50" relaycount="1" stroke="BACK"/></RECORD></RECORDS></RECORDLIST><RECORDLIST course="LCM" gender="M" name="13-14 regional"><AGEGROUP agegroupid="2" agemin="13" agemax="14"/><RECORDS><RECORD swimtime="00:00:25.30"><SWIMSTYLE swimstyleid="9" distance="50" relaycount="1" stroke="FREE"/></RECORD></RECORDS></RECORDLIST></RECORDLISTS></LENEX>"#;
+
+        let lenex = Lenex::try_from(xml.to_string());
+        assert!(lenex.is_ok());
+        let lenex = lenex.unwrap();
+        assert!(lenex.meets.is_empty());
+
+        let free = SwimStyle {
+            distance: 50,
+            relay_count: 1,
+            stroke: crate::model::Stroke::Free,
+            ..Default::default()
+        };
+
+        let record = lenex.record_for(&free, &Course::LCM, &Gender::Male, Some(13));
+        assert_eq!(Some(SwimTime::Time(2210)), record.unwrap().swim_time);
+        assert_eq!(
+            3,
+            lenex
+                .records_for(&free, &Course::LCM, &Gender::Male, Some(14))
+                .len()
+        );
+        assert_eq!(
+            1,
+            lenex
+                .records_for(&free, &Course::LCM, &Gender::Male, Some(12))
+                .len()
+        );
+
+        let record = lenex.record_for(&free, &Course::LCM, &Gender::Male, None);
+        assert_eq!(Some(SwimTime::Time(2210)), record.unwrap().swim_time);
+
+        assert!(lenex
+            .record_for(&free, &Course::SCM, &Gender::Male, None)
+            .is_none());
+        assert!(lenex
+            .record_for(&free, &Course::LCM, &Gender::Female, None)
+            .is_none());
+        let record = lenex.record_for(&free, &Course::LCM, &Gender::Male, Some(15));
+        assert_eq!(Some(SwimTime::Time(2210)), record.unwrap().swim_time);
+    }
 }
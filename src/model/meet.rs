@@ -1,13 +1,43 @@
+use chrono::{NaiveDate, NaiveTime};
 use serde::{Deserialize, Serialize};
 
-use crate::collection::Collection;
+use crate::{
+    collection::Collection,
+    serialization::{
+        preserve::{
+            preserve_child, preserve_collection, restore_child, restore_collection, Node, Preserve,
+        },
+        serde_time,
+    },
+};
+
+pub use self::{
+    entry_type::EntryType, start_method::StartMethod, status::MeetStatus, touchpad::Touchpad,
+};
 
 use super::{
-    age_date::AgeDate, club::Club, course::Course, fee::Fee, pool::Pool, session::Session,
-    timing::Timing, Facility, PointTable, Qualify,
+    age_date::{AgeDate, AgeDateType},
+    athlete::Athlete,
+    club::Club,
+    contact::Contact,
+    course::Course,
+    extra::Extra,
+    fee::{Fee, FeeType},
+    judge::RosterEntry,
+    official::Official,
+    pool::Pool,
+    session::Session,
+    start_list::{Competitor, StartListEntry},
+    timing::Timing,
+    Bank, Facility, PointTable, Qualify,
 };
 
-#[derive(Serialize, Deserialize, PartialEq, Default, Debug)]
+mod entry_type;
+mod start_method;
+mod status;
+mod touchpad;
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
 #[serde(rename = "MEET")]
 pub struct Meet {
     name: String,
@@ -28,13 +58,65 @@ pub struct Meet {
     reserve_count: Option<u32>,
 
     #[serde(rename = "startmethod")]
-    start_method: Option<u32>,
+    start_method: Option<StartMethod>,
 
     timing: Option<Timing>,
 
+    altitude: Option<u32>,
+
+    deadline: Option<NaiveDate>,
+
+    #[serde(rename = "deadlinetime", default, with = "serde_time::optional")]
+    deadline_time: Option<NaiveTime>,
+
+    #[serde(rename = "entrystartdate")]
+    entry_start_date: Option<NaiveDate>,
+
+    #[serde(rename = "entrytype")]
+    entry_type: Option<EntryType>,
+
+    #[serde(rename = "hostclub")]
+    host_club: Option<String>,
+
+    #[serde(rename = "hostclub.url")]
+    host_club_url: Option<String>,
+
+    #[serde(rename = "maxentriesathlete")]
+    max_entries_athlete: Option<u32>,
+
+    #[serde(rename = "maxentriesrelay")]
+    max_entries_relay: Option<u32>,
+
+    organizer: Option<String>,
+
+    #[serde(rename = "organizer.url")]
+    organizer_url: Option<String>,
+
+    #[serde(rename = "result.url")]
+    result_url: Option<String>,
+
+    state: Option<String>,
+
+    status: Option<MeetStatus>,
+
+    swrid: Option<u32>,
+
+    touchpad: Option<Touchpad>,
+
+    r#type: Option<String>,
+
+    #[serde(rename = "withdrawuntil")]
+    withdraw_until: Option<NaiveDate>,
+
     #[serde(rename = "AGEDATE")]
     age_date: Option<AgeDate>,
 
+    #[serde(rename = "BANK")]
+    bank: Option<Bank>,
+
+    #[serde(rename = "CONTACT")]
+    contact: Option<Contact>,
+
     #[serde(rename = "POOL")]
     pool: Option<Pool>,
 
@@ -53,8 +135,14 @@ pub struct Meet {
     #[serde(rename = "SESSIONS")]
     sessions: Collection<Session>,
 
+    #[serde(rename = "OFFICIALS", default, skip_serializing_if = "Vec::is_empty")]
+    officials: Collection<Official>,
+
     #[serde(rename = "CLUBS")]
     clubs: Collection<Club>,
+
+    #[serde(skip)]
+    extra: Extra,
 }
 
 impl Meet {
@@ -67,11 +155,529 @@ impl Meet {
             ..Default::default()
         }
     }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn name_en(&self) -> Option<&str> {
+        self.name_en.as_deref()
+    }
+
+    pub fn city(&self) -> &str {
+        &self.city
+    }
+
+    pub fn city_en(&self) -> Option<&str> {
+        self.city_en.as_deref()
+    }
+
+    pub fn nation(&self) -> &str {
+        &self.nation
+    }
+
+    pub fn course(&self) -> Option<&Course> {
+        self.course.as_ref()
+    }
+
+    pub fn reserve_count(&self) -> Option<u32> {
+        self.reserve_count
+    }
+
+    pub fn start_method(&self) -> Option<&StartMethod> {
+        self.start_method.as_ref()
+    }
+
+    pub fn timing(&self) -> Option<&Timing> {
+        self.timing.as_ref()
+    }
+
+    pub fn altitude(&self) -> Option<u32> {
+        self.altitude
+    }
+
+    pub fn deadline(&self) -> Option<NaiveDate> {
+        self.deadline
+    }
+
+    pub fn deadline_time(&self) -> Option<NaiveTime> {
+        self.deadline_time
+    }
+
+    pub fn entry_start_date(&self) -> Option<NaiveDate> {
+        self.entry_start_date
+    }
+
+    pub fn entry_type(&self) -> Option<&EntryType> {
+        self.entry_type.as_ref()
+    }
+
+    pub fn host_club(&self) -> Option<&str> {
+        self.host_club.as_deref()
+    }
+
+    pub fn host_club_url(&self) -> Option<&str> {
+        self.host_club_url.as_deref()
+    }
+
+    pub fn max_entries_athlete(&self) -> Option<u32> {
+        self.max_entries_athlete
+    }
+
+    pub fn max_entries_relay(&self) -> Option<u32> {
+        self.max_entries_relay
+    }
+
+    pub fn organizer(&self) -> Option<&str> {
+        self.organizer.as_deref()
+    }
+
+    pub fn organizer_url(&self) -> Option<&str> {
+        self.organizer_url.as_deref()
+    }
+
+    pub fn result_url(&self) -> Option<&str> {
+        self.result_url.as_deref()
+    }
+
+    pub fn state(&self) -> Option<&str> {
+        self.state.as_deref()
+    }
+
+    pub fn status(&self) -> Option<&MeetStatus> {
+        self.status.as_ref()
+    }
+
+    pub fn swrid(&self) -> Option<u32> {
+        self.swrid
+    }
+
+    pub fn touchpad(&self) -> Option<&Touchpad> {
+        self.touchpad.as_ref()
+    }
+
+    pub fn r#type(&self) -> Option<&str> {
+        self.r#type.as_deref()
+    }
+
+    pub fn withdraw_until(&self) -> Option<NaiveDate> {
+        self.withdraw_until
+    }
+
+    pub fn bank(&self) -> Option<&Bank> {
+        self.bank.as_ref()
+    }
+
+    pub fn contact(&self) -> Option<&Contact> {
+        self.contact.as_ref()
+    }
+
+    pub fn age_date(&self) -> Option<&AgeDate> {
+        self.age_date.as_ref()
+    }
+
+    pub fn pool(&self) -> Option<&Pool> {
+        self.pool.as_ref()
+    }
+
+    pub fn facility(&self) -> Option<&Facility> {
+        self.facility.as_ref()
+    }
+
+    pub fn point_table(&self) -> Option<&PointTable> {
+        self.point_table.as_ref()
+    }
+
+    pub fn qualify(&self) -> Option<&Qualify> {
+        self.qualify.as_ref()
+    }
+
+    pub fn fees(&self) -> &[Fee] {
+        &self.fees
+    }
+
+    pub fn fees_mut(&mut self) -> &mut Vec<Fee> {
+        &mut self.fees
+    }
+
+    /// The first fee of the meet of this type.
+    pub fn fee(&self, r#type: &FeeType) -> Option<&Fee> {
+        self.fees.iter().find(|fee| fee.r#type() == Some(r#type))
+    }
+
+    pub fn sessions(&self) -> &[Session] {
+        &self.sessions
+    }
+
+    pub fn sessions_mut(&mut self) -> &mut Vec<Session> {
+        &mut self.sessions
+    }
+
+    pub fn officials(&self) -> &[Official] {
+        &self.officials
+    }
+
+    pub fn officials_mut(&mut self) -> &mut Vec<Official> {
+        &mut self.officials
+    }
+
+    pub fn official(&self, id: u32) -> Option<&Official> {
+        self.officials.iter().find(|official| official.id == id)
+    }
+
+    pub fn clubs(&self) -> &[Club] {
+        &self.clubs
+    }
+
+    pub fn clubs_mut(&mut self) -> &mut Vec<Club> {
+        &mut self.clubs
+    }
+
+    pub fn with_name_en(&mut self, name: String) -> &mut Self {
+        self.name_en = Some(name);
+
+        self
+    }
+
+    pub fn with_city_en(&mut self, city: String) -> &mut Self {
+        self.city_en = Some(city);
+
+        self
+    }
+
+    pub fn with_course(&mut self, course: Course) -> &mut Self {
+        self.course = Some(course);
+
+        self
+    }
+
+    pub fn with_reserve_count(&mut self, reserve_count: u32) -> &mut Self {
+        self.reserve_count = Some(reserve_count);
+
+        self
+    }
+
+    pub fn with_start_method(&mut self, start_method: StartMethod) -> &mut Self {
+        self.start_method = Some(start_method);
+
+        self
+    }
+
+    pub fn with_timing(&mut self, timing: Timing) -> &mut Self {
+        self.timing = Some(timing);
+
+        self
+    }
+
+    pub fn with_altitude(&mut self, altitude: u32) -> &mut Self {
+        self.altitude = Some(altitude);
+
+        self
+    }
+
+    pub fn with_deadline(&mut self, deadline: NaiveDate) -> &mut Self {
+        self.deadline = Some(deadline);
+
+        self
+    }
+
+    pub fn with_deadline_time(&mut self, deadline_time: NaiveTime) -> &mut Self {
+        self.deadline_time = Some(deadline_time);
+
+        self
+    }
+
+    pub fn with_entry_start_date(&mut self, entry_start_date: NaiveDate) -> &mut Self {
+        self.entry_start_date = Some(entry_start_date);
+
+        self
+    }
+
+    pub fn with_entry_type(&mut self, entry_type: EntryType) -> &mut Self {
+        self.entry_type = Some(entry_type);
+
+        self
+    }
+
+    pub fn with_host_club(&mut self, host_club: String) -> &mut Self {
+        self.host_club = Some(host_club);
+
+        self
+    }
+
+    pub fn with_host_club_url(&mut self, host_club_url: String) -> &mut Self {
+        self.host_club_url = Some(host_club_url);
+
+        self
+    }
+
+    pub fn with_max_entries_athlete(&mut self, max_entries_athlete: u32) -> &mut Self {
+        self.max_entries_athlete = Some(max_entries_athlete);
+
+        self
+    }
+
+    pub fn with_max_entries_relay(&mut self, max_entries_relay: u32) -> &mut Self {
+        self.max_entries_relay = Some(max_entries_relay);
+
+        self
+    }
+
+    pub fn with_organizer(&mut self, organizer: String) -> &mut Self {
+        self.organizer = Some(organizer);
+
+        self
+    }
+
+    pub fn with_organizer_url(&mut self, organizer_url: String) -> &mut Self {
+        self.organizer_url = Some(organizer_url);
+
+        self
+    }
+
+    pub fn with_result_url(&mut self, result_url: String) -> &mut Self {
+        self.result_url = Some(result_url);
+
+        self
+    }
+
+    pub fn with_state(&mut self, state: String) -> &mut Self {
+        self.state = Some(state);
+
+        self
+    }
+
+    pub fn with_status(&mut self, status: MeetStatus) -> &mut Self {
+        self.status = Some(status);
+
+        self
+    }
+
+    pub fn with_swrid(&mut self, swrid: u32) -> &mut Self {
+        self.swrid = Some(swrid);
+
+        self
+    }
+
+    pub fn with_touchpad(&mut self, touchpad: Touchpad) -> &mut Self {
+        self.touchpad = Some(touchpad);
+
+        self
+    }
+
+    pub fn with_type(&mut self, r#type: String) -> &mut Self {
+        self.r#type = Some(r#type);
+
+        self
+    }
+
+    pub fn with_withdraw_until(&mut self, withdraw_until: NaiveDate) -> &mut Self {
+        self.withdraw_until = Some(withdraw_until);
+
+        self
+    }
+
+    pub fn with_bank(&mut self, bank: Bank) -> &mut Self {
+        self.bank = Some(bank);
+
+        self
+    }
+
+    pub fn with_contact(&mut self, contact: Contact) -> &mut Self {
+        self.contact = Some(contact);
+
+        self
+    }
+
+    pub fn with_age_date(&mut self, age_date: AgeDate) -> &mut Self {
+        self.age_date = Some(age_date);
+
+        self
+    }
+
+    pub fn with_pool(&mut self, pool: Pool) -> &mut Self {
+        self.pool = Some(pool);
+
+        self
+    }
+
+    pub fn with_facility(&mut self, facility: Facility) -> &mut Self {
+        self.facility = Some(facility);
+
+        self
+    }
+
+    pub fn with_point_table(&mut self, point_table: PointTable) -> &mut Self {
+        self.point_table = Some(point_table);
+
+        self
+    }
+
+    pub fn with_qualify(&mut self, qualify: Qualify) -> &mut Self {
+        self.qualify = Some(qualify);
+
+        self
+    }
+
+    pub fn add_fee(&mut self, fee: Fee) -> &mut Self {
+        self.fees.push(fee);
+
+        self
+    }
+
+    pub fn add_session(&mut self, session: Session) -> &mut Self {
+        self.sessions.push(session);
+
+        self
+    }
+
+    pub fn add_club(&mut self, club: Club) -> &mut Self {
+        self.clubs.push(club);
+
+        self
+    }
+
+    pub fn add_official(&mut self, official: Official) -> &mut Self {
+        self.officials.push(official);
+
+        self
+    }
+
+    /// Returns the officials judging the session numbered `session_number`, sorted by role and
+    /// number.
+    pub fn roster(&self, session_number: u32) -> Vec<RosterEntry<'_>> {
+        let mut roster: Vec<_> = self
+            .sessions
+            .iter()
+            .filter(|session| session.number() == session_number)
+            .flat_map(|session| session.judges())
+            .map(|judge| RosterEntry {
+                judge,
+                official: self.official(judge.official_id),
+            })
+            .collect();
+
+        roster.sort_by_key(|entry| {
+            (
+                entry.judge.role.is_none(),
+                entry.judge.role,
+                entry.judge.number,
+            )
+        });
+
+        roster
+    }
+
+    /// Returns the start list of the heat identified by `heat_id`, sorted by lane.
+    ///
+    /// Entries without a lane are listed last.
+    pub fn start_list(&self, heat_id: u32) -> Vec<StartListEntry<'_>> {
+        let mut start_list = Vec::new();
+
+        for club in self.clubs.iter() {
+            for athlete in club.athletes() {
+                start_list.extend(
+                    athlete
+                        .entries()
+                        .iter()
+                        .filter(|entry| entry.heat_id() == Some(heat_id))
+                        .map(|entry| {
+                            StartListEntry::new(club, Competitor::Athlete(athlete), entry)
+                        }),
+                );
+            }
+
+            for relay in club.relays() {
+                start_list.extend(
+                    relay
+                        .entries()
+                        .iter()
+                        .filter(|entry| entry.heat_id() == Some(heat_id))
+                        .map(|entry| StartListEntry::new(club, Competitor::Relay(relay), entry)),
+                );
+            }
+        }
+
+        start_list.sort_by_key(|entry| (entry.lane.is_none(), entry.lane));
+
+        start_list
+    }
+
+    /// The date ages are computed on: the age date of the meet, or the exact age on the first
+    /// day of the meet when it has none.
+    pub fn effective_age_date(&self) -> Option<AgeDate> {
+        self.age_date.clone().or_else(|| {
+            self.sessions
+                .iter()
+                .map(|session| session.date())
+                .min()
+                .map(|value| AgeDate {
+                    value,
+                    r#type: AgeDateType::Date,
+                    extra: Extra::default(),
+                })
+        })
+    }
+
+    /// The age of the athlete for this meet, `None` for an athlete without birth date or a meet
+    /// without age date nor sessions, or when the age date needs a full birth date and only the
+    /// year of birth is known.
+    pub fn athlete_age(&self, athlete: &Athlete) -> Option<u16> {
+        let birth_date = athlete.birth_date()?;
+
+        self.effective_age_date()?.age(birth_date)
+    }
+
+    /// The attributes and child elements of this element unknown to the model.
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
+}
+
+impl Preserve for Meet {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_child(self.age_date.as_mut(), node, "AGEDATE");
+        preserve_child(self.bank.as_mut(), node, "BANK");
+        preserve_child(self.contact.as_mut(), node, "CONTACT");
+        preserve_child(self.pool.as_mut(), node, "POOL");
+        preserve_child(self.facility.as_mut(), node, "FACILITY");
+        preserve_child(self.point_table.as_mut(), node, "POINTTABLE");
+        preserve_collection(&mut self.fees, node, "FEES");
+        preserve_child(self.qualify.as_mut(), node, "QUALIFY");
+        preserve_collection(&mut self.sessions, node, "SESSIONS");
+        preserve_collection(&mut self.officials, node, "OFFICIALS");
+        preserve_collection(&mut self.clubs, node, "CLUBS");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_child(self.age_date.as_ref(), node, "AGEDATE");
+        restore_child(self.bank.as_ref(), node, "BANK");
+        restore_child(self.contact.as_ref(), node, "CONTACT");
+        restore_child(self.pool.as_ref(), node, "POOL");
+        restore_child(self.facility.as_ref(), node, "FACILITY");
+        restore_child(self.point_table.as_ref(), node, "POINTTABLE");
+        restore_collection(&self.fees, node, "FEES");
+        restore_child(self.qualify.as_ref(), node, "QUALIFY");
+        restore_collection(&self.sessions, node, "SESSIONS");
+        restore_collection(&self.officials, node, "OFFICIALS");
+        restore_collection(&self.clubs, node, "CLUBS");
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use chrono::NaiveDate;
+
     use super::*;
+    use crate::model::{Gender, JudgeRole, SwimTime};
 
     #[test]
     fn test_deserialize_fees() {
@@ -95,6 +701,116 @@ mod tests {
         assert_eq!(1, meet.sessions.len());
     }
 
+    #[test]
+    fn test_start_list() {
+        let result = fast_xml::de::from_str::<Meet>(
+            r#"<MEET name="" city="" nation=""><SESSIONS/><CLUBS><CLUB clubid="1" name="A"><ATHLETES><ATHLETE athleteid="1" firstname="" lastname="" gender="F" birthdate="2010-01-01"><ENTRIES><ENTRY eventid="1" heatid="10" lane="5" entrytime="00:00:30.00"/><ENTRY eventid="2" heatid="20" lane="1"/></ENTRIES></ATHLETE></ATHLETES><RELAYS><RELAY number="1" gender="F"><ENTRIES><ENTRY eventid="1" heatid="10"/></ENTRIES></RELAY></RELAYS></CLUB><CLUB clubid="2" name="B"><ATHLETES><ATHLETE athleteid="2" firstname="" lastname="" gender="F" birthdate="2010-01-01"><ENTRIES><ENTRY eventid="1" heatid="10" lane="2"/></ENTRIES></ATHLETE></ATHLETES></CLUB></CLUBS></MEET>"#,
+        );
+        assert!(result.is_ok());
+
+        let meet = result.unwrap();
+        let start_list = meet.start_list(10);
+        assert_eq!(3, start_list.len());
+
+        assert_eq!(Some(2), start_list[0].lane);
+        assert_eq!("B", start_list[0].club.name());
+        assert!(matches!(start_list[0].competitor, Competitor::Athlete(a) if a.id() == 2));
+
+        assert_eq!(Some(5), start_list[1].lane);
+        assert_eq!(Some(SwimTime::Time(3000)), start_list[1].entry_time);
+
+        assert_eq!(None, start_list[2].lane);
+        assert!(matches!(start_list[2].competitor, Competitor::Relay(_)));
+    }
+
+    #[test]
+    fn test_roster() {
+        let result = fast_xml::de::from_str::<Meet>(
+            r#"<MEET name="" city="" nation=""><SESSIONS><SESSION number="1" date="2024-03-02"><EVENTS/><JUDGES><JUDGE officialid="2" role="TIK" number="2"/><JUDGE officialid="3" role="TIK" number="1"/><JUDGE officialid="1" role="REF"/><JUDGE officialid="9" role="STA"/></JUDGES></SESSION><SESSION number="2" date="2024-03-02"><EVENTS/></SESSION></SESSIONS><OFFICIALS><OFFICIAL officialid="1" firstname="Ann" lastname="Roy"/><OFFICIAL officialid="2" firstname="Ben" lastname="Roy"/><OFFICIAL officialid="3" firstname="Cam" lastname="Roy"/></OFFICIALS><CLUBS/></MEET>"#,
+        );
+        assert!(result.is_ok());
+
+        let meet = result.unwrap();
+        assert_eq!(3, meet.officials().len());
+        assert_eq!(4, meet.sessions()[0].judges().len());
+
+        let roster = meet.roster(1);
+        let officials: Vec<_> = roster
+            .iter()
+            .map(|entry| entry.official.map(|official| official.first_name.as_str()))
+            .collect();
+        assert_eq!(vec![Some("Ann"), None, Some("Cam"), Some("Ben")], officials);
+        assert_eq!(Some(JudgeRole::Starter), roster[1].judge.role);
+
+        assert!(meet.roster(2).is_empty());
+    }
+
+    #[test]
+    fn test_getters() {
+        let result = fast_xml::de::from_str::<Meet>(
+            r#"<MEET name="Open" name.en="Open meet" city="Montréal" nation="CAN" course="LCM" timing="AUTOMATIC"><POOL lanemin="1" lanemax="8"/><SESSIONS/><CLUBS><CLUB clubid="1" name="Club"><ATHLETES/></CLUB></CLUBS></MEET>"#,
+        );
+        assert!(result.is_ok());
+
+        let meet = result.unwrap();
+        assert_eq!("Open", meet.name());
+        assert_eq!(Some("Open meet"), meet.name_en());
+        assert_eq!("Montréal", meet.city());
+        assert_eq!("CAN", meet.nation());
+        assert_eq!(Some(&Course::LCM), meet.course());
+        assert_eq!(Some(&Timing::Automatic), meet.timing());
+        assert_eq!(Some(8), meet.pool().unwrap().number_of_lane());
+        assert_eq!("Club", meet.clubs().first().unwrap().name());
+    }
+
+    #[test]
+    fn test_athlete_age() {
+        let athlete = Athlete::new(
+            1,
+            "Jane".into(),
+            "Doe".into(),
+            Gender::Female,
+            NaiveDate::from_ymd_opt(2010, 10, 15).unwrap().into(),
+        );
+
+        let mut meet = Meet::new("Open".into(), "CAN".into(), "Montréal".into(), Vec::new());
+        assert_eq!(None, meet.athlete_age(&athlete));
+
+        meet.add_session(Session::new(
+            1,
+            NaiveDate::from_ymd_opt(2023, 2, 11).unwrap(),
+            Vec::new(),
+        ));
+        assert_eq!(Some(12), meet.athlete_age(&athlete));
+
+        meet.with_age_date(AgeDate {
+            value: NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+            r#type: AgeDateType::Year,
+            ..Default::default()
+        });
+        assert_eq!(Some(13), meet.athlete_age(&athlete));
+    }
+
+    #[test]
+    fn test_serialize_built() {
+        let mut club = Club::new(1, "Club".into());
+        club.with_nation("CAN".into());
+
+        let mut meet = Meet::new("Open".into(), "CAN".into(), "Montréal".into(), Vec::new());
+        meet.with_course(Course::SCM)
+            .with_result_url("https://example.com/results".into())
+            .add_fee(Fee::new(FeeType::Athlete, 1500))
+            .add_session(Session::new(1, NaiveDate::default(), Vec::new()))
+            .add_club(club);
+
+        let result = fast_xml::se::to_string(&meet);
+        assert!(result.is_ok());
+        assert_eq!(
+            r#"<MEET name="Open" city="Montréal" nation="CAN" course="SCM" result.url="https://example.com/results"><FEES><FEE type="ATHLETE" value="1500"/></FEES><SESSIONS><SESSION date="1970-01-01" number="1"><EVENTS/></SESSION></SESSIONS><CLUBS><CLUB clubid="1" name="Club" nation="CAN"><ATHLETES/></CLUB></CLUBS></MEET>"#,
+            &result.unwrap()
+        );
+    }
+
     #[test]
     fn test_serialize_fees() {
         let meet = Meet {
@@ -126,4 +842,28 @@ mod tests {
             &result.unwrap()
         );
     }
+
+    #[test]
+    fn test_round_trip_attributes() {
+        let xml = r#"<MEET name="Open" city="Montréal" nation="CAN" startmethod="1" altitude="30" deadline="2023-01-31" deadlinetime="23:59" entrystartdate="2023-01-01" entrytype="INVITATION" hostclub="Club" hostclub.url="https://club.example.com" maxentriesathlete="5" maxentriesrelay="2" organizer="Federation" organizer.url="https://federation.example.com" result.url="https://results.example.com" state="QC" status="ENTRIES" swrid="12345" touchpad="ONESIDE" type="CAN.PROV" withdrawuntil="2023-02-05"><BANK accountholder="Club" iban="CA00 0000" name="Bank"/><CONTACT name="Jane Doe" country="CA" email="jane@example.com" internet="https://club.example.com"/><FEES/><SESSIONS/><CLUBS/></MEET>"#;
+
+        let result = fast_xml::de::from_str::<Meet>(xml);
+        assert!(result.is_ok());
+
+        let meet = result.unwrap();
+        assert_eq!(Some(&StartMethod::OneStart), meet.start_method());
+        assert_eq!(Some(30), meet.altitude());
+        assert_eq!(NaiveDate::from_ymd_opt(2023, 1, 31), meet.deadline());
+        assert_eq!(NaiveTime::from_hms_opt(23, 59, 0), meet.deadline_time());
+        assert_eq!(Some(&EntryType::Invitation), meet.entry_type());
+        assert_eq!(Some("https://club.example.com"), meet.host_club_url());
+        assert_eq!(Some(5), meet.max_entries_athlete());
+        assert_eq!(Some(&MeetStatus::Entries), meet.status());
+        assert_eq!(Some(&Touchpad::OneSide), meet.touchpad());
+        assert_eq!(Some("CAN.PROV"), meet.r#type());
+        assert_eq!(Some("CA00 0000"), meet.bank().unwrap().iban.as_deref());
+        assert_eq!(Some("Jane Doe"), meet.contact().unwrap().name.as_deref());
+
+        assert_eq!(xml, fast_xml::se::to_string(&meet).unwrap());
+    }
 }
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
+
+#[derive(Serialize, Deserialize, IntoStaticStr, PartialEq, Debug, Clone)]
+#[serde(rename_all = "UPPERCASE", into = "&str")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum EntryType {
+    Open,
+    Invitation,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fast_xml::{de, se};
+
+    #[test]
+    fn serialize() {
+        let value = EntryType::Invitation;
+        let result = se::to_string(&value);
+        assert!(result.is_ok());
+
+        assert_eq!("INVITATION", result.unwrap());
+    }
+
+    #[test]
+    fn deserialize() {
+        let result = de::from_str::<EntryType>("OPEN");
+        assert!(result.is_ok());
+
+        assert_eq!(EntryType::Open, result.unwrap());
+    }
+}
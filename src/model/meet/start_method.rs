@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
+
+/// How many starts an athlete is allowed before being disqualified.
+#[derive(Serialize, Deserialize, IntoStaticStr, PartialEq, Debug, Clone)]
+#[serde(into = "&str")]
+pub enum StartMethod {
+    /// The first false start disqualifies.
+    #[serde(rename(deserialize = "1"))]
+    #[strum(serialize = "1")]
+    OneStart,
+
+    /// A false start is allowed before disqualifying.
+    #[serde(rename(deserialize = "2"))]
+    #[strum(serialize = "2")]
+    TwoStarts,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fast_xml::{de, se};
+
+    #[test]
+    fn serialize() {
+        let value = StartMethod::TwoStarts;
+        let result = se::to_string(&value);
+        assert!(result.is_ok());
+
+        assert_eq!("2", result.unwrap());
+    }
+
+    #[test]
+    fn deserialize() {
+        let result = de::from_str::<StartMethod>("1");
+        assert!(result.is_ok());
+
+        assert_eq!(StartMethod::OneStart, result.unwrap());
+    }
+}
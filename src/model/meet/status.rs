@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
+
+#[derive(Serialize, Deserialize, IntoStaticStr, PartialEq, Debug, Clone)]
+#[serde(rename_all = "UPPERCASE", into = "&str")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum MeetStatus {
+    Entries,
+    Seeded,
+    Running,
+    Official,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fast_xml::{de, se};
+
+    #[test]
+    fn serialize() {
+        let value = MeetStatus::Seeded;
+        let result = se::to_string(&value);
+        assert!(result.is_ok());
+
+        assert_eq!("SEEDED", result.unwrap());
+    }
+
+    #[test]
+    fn deserialize() {
+        let result = de::from_str::<MeetStatus>("OFFICIAL");
+        assert!(result.is_ok());
+
+        assert_eq!(MeetStatus::Official, result.unwrap());
+    }
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
+
+#[derive(Serialize, Deserialize, IntoStaticStr, PartialEq, Debug, Clone)]
+#[serde(rename_all = "UPPERCASE", into = "&str")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum Touchpad {
+    None,
+    OneSide,
+    BothSide,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fast_xml::{de, se};
+
+    #[test]
+    fn serialize() {
+        let value = Touchpad::OneSide;
+        let result = se::to_string(&value);
+        assert!(result.is_ok());
+
+        assert_eq!("ONESIDE", result.unwrap());
+    }
+
+    #[test]
+    fn deserialize() {
+        let result = de::from_str::<Touchpad>("BOTHSIDE");
+        assert!(result.is_ok());
+
+        assert_eq!(Touchpad::BothSide, result.unwrap());
+    }
+}
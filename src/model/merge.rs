@@ -0,0 +1,491 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+    mem,
+};
+
+use chrono::NaiveDate;
+
+use super::{athlete::Athlete, club::Club, entry::Entry, event::Event, lenex::Lenex, meet::Meet};
+
+/// Something [`Lenex::merge_entries`] could not merge without losing or overwriting data.
+#[derive(PartialEq, Debug, Clone)]
+pub enum MergeConflict {
+    /// No meet of the document has the same name, city and dates.
+    MeetNotFound { name: String, city: String },
+
+    /// No event matches the event, by id or by number, swim style and gender. Its entries are
+    /// dropped.
+    EventNotFound { event_id: u32, number: u32 },
+
+    /// The event id is used by another race than the one it describes in the merged file. Its
+    /// entries are dropped.
+    EventMismatch { event_id: u32, number: u32 },
+
+    /// The athlete has the license of another athlete of the club. Its entries are dropped.
+    AthleteMismatch { club: String, license: String },
+
+    /// The competitor already has a different entry for the event. The existing entry is kept.
+    Entry {
+        club: String,
+        competitor: String,
+        event_id: u32,
+    },
+}
+
+impl Display for MergeConflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MergeConflict::MeetNotFound { name, city } => {
+                write!(f, "meet {name} in {city} not found")
+            }
+            MergeConflict::EventNotFound { event_id, number } => {
+                write!(f, "event {number} (id {event_id}) not found")
+            }
+            MergeConflict::EventMismatch { event_id, number } => {
+                write!(f, "event {number} (id {event_id}) describes another race")
+            }
+            MergeConflict::AthleteMismatch { club, license } => {
+                write!(f, "license {license} of {club} belongs to another athlete")
+            }
+            MergeConflict::Entry {
+                club,
+                competitor,
+                event_id,
+            } => write!(
+                f,
+                "{competitor} of {club} already has another entry for event {event_id}"
+            ),
+        }
+    }
+}
+
+impl Lenex {
+    /// Merges the clubs, athletes, relays and entries of an entries file sent for one of the
+    /// meets of this document.
+    ///
+    /// Meets are matched by name, city and session dates, events by id or by number, swim style
+    /// and gender, when both give one. Clubs are the same when they share a code, or a name and nation, and athletes
+    /// when they share a license, or a name, birth date and gender. New clubs and athletes get
+    /// fresh ids when theirs are already used, and entries are renumbered to the events of this
+    /// document.
+    ///
+    /// Nothing already in this document is overwritten: everything that cannot be merged is
+    /// returned as a conflict.
+    pub fn merge_entries(&mut self, mut other: Lenex) -> Vec<MergeConflict> {
+        let mut conflicts = Vec::new();
+
+        for other_meet in mem::take(&mut *other.meets) {
+            match self
+                .meets
+                .iter_mut()
+                .find(|meet| is_same_meet(meet, &other_meet))
+            {
+                Some(meet) => merge_meet(meet, other_meet, &mut conflicts),
+                None => conflicts.push(MergeConflict::MeetNotFound {
+                    name: other_meet.name().to_string(),
+                    city: other_meet.city().to_string(),
+                }),
+            }
+        }
+
+        conflicts
+    }
+}
+
+fn dates(meet: &Meet) -> Option<(NaiveDate, NaiveDate)> {
+    let first = meet.sessions().iter().map(|session| session.date()).min()?;
+    let last = meet.sessions().iter().map(|session| session.date()).max()?;
+
+    Some((first, last))
+}
+
+fn is_same_meet(meet: &Meet, other: &Meet) -> bool {
+    meet.name() == other.name() && meet.city() == other.city() && dates(meet) == dates(other)
+}
+
+/// Events without a gender match events of any gender.
+fn is_same_event(event: &Event, other: &Event) -> bool {
+    event.number() == other.number()
+        && event.swim_style().is_same_race(other.swim_style())
+        && match (event.gender(), other.gender()) {
+            (Some(gender), Some(other_gender)) => gender == other_gender,
+            _ => true,
+        }
+}
+
+fn is_same_club(club: &Club, other: &Club) -> bool {
+    match (club.code(), other.code()) {
+        (Some(code), Some(other_code)) => code == other_code,
+        _ => club.name() == other.name() && club.nation() == other.nation(),
+    }
+}
+
+fn is_same_person(athlete: &Athlete, other: &Athlete) -> bool {
+    athlete.first_name() == other.first_name()
+        && athlete.last_name() == other.last_name()
+        && athlete.birth_date() == other.birth_date()
+        && athlete.gender() == other.gender()
+}
+
+enum AthleteMatch {
+    Found(usize),
+    Mismatch,
+    None,
+}
+
+fn find_athlete(club: &Club, other: &Athlete) -> AthleteMatch {
+    let athletes = club.athletes();
+
+    if let Some(license) = other.license() {
+        if let Some(index) = athletes
+            .iter()
+            .position(|athlete| athlete.license() == Some(license))
+        {
+            return match is_same_person(&athletes[index], other) {
+                true => AthleteMatch::Found(index),
+                false => AthleteMatch::Mismatch,
+            };
+        }
+    }
+
+    match athletes
+        .iter()
+        .position(|athlete| is_same_person(athlete, other))
+    {
+        Some(index) => AthleteMatch::Found(index),
+        None => AthleteMatch::None,
+    }
+}
+
+fn full_name(athlete: &Athlete) -> String {
+    format!("{} {}", athlete.first_name(), athlete.last_name())
+}
+
+/// Maps the event ids of the merged meet to the ids of the same events in `meet`.
+fn event_ids(meet: &Meet, other: &Meet, conflicts: &mut Vec<MergeConflict>) -> HashMap<u32, u32> {
+    let events: Vec<&Event> = meet
+        .sessions()
+        .iter()
+        .flat_map(|session| session.events())
+        .collect();
+    let mut ids = HashMap::new();
+
+    for other_event in other.sessions().iter().flat_map(|session| session.events()) {
+        let by_id = events.iter().find(|event| event.id == other_event.id);
+        let matched = match by_id {
+            Some(event) if is_same_event(event, other_event) => Some(event),
+            _ => events
+                .iter()
+                .find(|event| is_same_event(event, other_event)),
+        };
+
+        match (matched, by_id) {
+            (Some(event), _) => {
+                ids.insert(other_event.id, event.id);
+            }
+            (None, Some(_)) => conflicts.push(MergeConflict::EventMismatch {
+                event_id: other_event.id,
+                number: other_event.number(),
+            }),
+            (None, None) => conflicts.push(MergeConflict::EventNotFound {
+                event_id: other_event.id,
+                number: other_event.number(),
+            }),
+        }
+    }
+
+    ids
+}
+
+/// Renumbers the entries to the events of the merged meet, dropping those of unknown events.
+fn remap_entries(entries: Vec<Entry>, event_ids: &HashMap<u32, u32>) -> Vec<Entry> {
+    entries
+        .into_iter()
+        .filter_map(|mut entry| {
+            let event_id = *event_ids.get(&entry.event_id())?;
+            entry.with_event_id(event_id);
+
+            Some(entry)
+        })
+        .collect()
+}
+
+/// Renumbers the relay positions to the athletes of the merged club. Positions of athletes that
+/// were not merged, such as mismatched ones, are left without athlete.
+fn remap_relay_positions(entry: &mut Entry, athlete_ids: &HashMap<u32, u32>) {
+    for position in entry.relay_positions_mut() {
+        position.athlete_id = position
+            .athlete_id
+            .and_then(|id| athlete_ids.get(&id).copied());
+    }
+}
+
+/// Adds the entries not already present, reporting those contradicting an existing entry.
+fn merge_into(
+    existing: &mut Vec<Entry>,
+    entries: Vec<Entry>,
+    club: &str,
+    competitor: &str,
+    conflicts: &mut Vec<MergeConflict>,
+) {
+    for entry in entries {
+        match existing
+            .iter()
+            .find(|other| other.event_id() == entry.event_id())
+        {
+            Some(other) if *other == entry => {}
+            Some(_) => conflicts.push(MergeConflict::Entry {
+                club: club.to_string(),
+                competitor: competitor.to_string(),
+                event_id: entry.event_id(),
+            }),
+            None => existing.push(entry),
+        }
+    }
+}
+
+fn merge_meet(meet: &mut Meet, mut other: Meet, conflicts: &mut Vec<MergeConflict>) {
+    let event_ids = event_ids(meet, &other, conflicts);
+    // Fresh ids start after those of both meets so they never collide with an id merged later.
+    let mut next_club_id = [&*meet, &other]
+        .iter()
+        .flat_map(|meet| meet.clubs())
+        .map(|club| club.id())
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let mut next_athlete_id = [&*meet, &other]
+        .iter()
+        .flat_map(|meet| meet.clubs())
+        .flat_map(|club| club.athletes())
+        .map(|athlete| athlete.id())
+        .max()
+        .unwrap_or(0)
+        + 1;
+
+    for mut other_club in mem::take(other.clubs_mut()) {
+        let other_athletes = mem::take(other_club.athletes_mut());
+        let other_relays = mem::take(other_club.relays_mut());
+
+        let club_index = match meet
+            .clubs()
+            .iter()
+            .position(|club| is_same_club(club, &other_club))
+        {
+            Some(index) => index,
+            None => {
+                if meet.clubs().iter().any(|club| club.id() == other_club.id()) {
+                    other_club.with_id(next_club_id);
+                    next_club_id += 1;
+                }
+
+                meet.add_club(other_club);
+
+                meet.clubs().len() - 1
+            }
+        };
+
+        let used_athlete_ids: Vec<u32> = meet
+            .clubs()
+            .iter()
+            .flat_map(|club| club.athletes())
+            .map(|athlete| athlete.id())
+            .collect();
+        let club = &mut meet.clubs_mut()[club_index];
+        let club_name = club.name().to_string();
+        let mut athlete_ids = HashMap::new();
+
+        for mut other_athlete in other_athletes {
+            let entries = remap_entries(mem::take(other_athlete.entries_mut()), &event_ids);
+
+            match find_athlete(club, &other_athlete) {
+                AthleteMatch::Found(index) => {
+                    let athlete = &mut club.athletes_mut()[index];
+                    athlete_ids.insert(other_athlete.id(), athlete.id());
+
+                    let name = full_name(athlete);
+                    merge_into(athlete.entries_mut(), entries, &club_name, &name, conflicts);
+                }
+                AthleteMatch::Mismatch => conflicts.push(MergeConflict::AthleteMismatch {
+                    club: club_name.clone(),
+                    license: other_athlete.license().unwrap_or_default().to_string(),
+                }),
+                AthleteMatch::None => {
+                    if used_athlete_ids.contains(&other_athlete.id()) {
+                        athlete_ids.insert(other_athlete.id(), next_athlete_id);
+                        other_athlete.with_id(next_athlete_id);
+                        next_athlete_id += 1;
+                    } else {
+                        athlete_ids.insert(other_athlete.id(), other_athlete.id());
+                    }
+
+                    *other_athlete.entries_mut() = entries;
+                    club.add_athlete(other_athlete);
+                }
+            }
+        }
+
+        for mut other_relay in other_relays {
+            let mut entries = remap_entries(mem::take(other_relay.entries_mut()), &event_ids);
+            for entry in entries.iter_mut() {
+                remap_relay_positions(entry, &athlete_ids);
+            }
+
+            match club.relays_mut().iter_mut().find(|relay| {
+                relay.number() == other_relay.number() && relay.gender() == other_relay.gender()
+            }) {
+                Some(relay) => {
+                    let name = format!("relay {}", relay.number().unwrap_or_default());
+                    merge_into(relay.entries_mut(), entries, &club_name, &name, conflicts);
+                }
+                None => {
+                    *other_relay.entries_mut() = entries;
+                    club.add_relay(other_relay);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{ClubType, Gender, SwimTime};
+
+    fn meet_file(clubs: &str) -> Lenex {
+        Lenex::try_from(format!(
+            r#"<LENEX version="3.0"><CONSTRUCTOR name="lenex-rs" registration="lenex-rs" version="0.1.0"><CONTACT name="lenex-rs" country="CA" email="lenex@example.com" internet="https://github.com/francisbr/lenex-rs"/></CONSTRUCTOR><MEETS><MEET name="Meet" city="Montreal" nation="CAN"><SESSIONS><SESSION date="2023-02-11" number="1"><EVENTS><EVENT eventid="1" number="1"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/></EVENT><EVENT eventid="2" number="2"><SWIMSTYLE swimstyleid="2" distance="100" relaycount="1" stroke="BACK"/></EVENT><EVENT eventid="3" number="3"><SWIMSTYLE swimstyleid="3" distance="50" relaycount="4" stroke="FREE"/></EVENT></EVENTS></SESSION></SESSIONS><CLUBS>{clubs}</CLUBS></MEET></MEETS></LENEX>"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn merge_new_club() {
+        let mut lenex = meet_file(
+            r#"<CLUB clubid="1" name="Club A" code="CLA"><ATHLETES><ATHLETE athleteid="1" firstname="Jane" lastname="Doe" gender="F" birthdate="2010-01-01"><ENTRIES><ENTRY eventid="1"/></ENTRIES></ATHLETE></ATHLETES></CLUB>"#,
+        );
+        let other = meet_file(
+            r#"<CLUB clubid="1" name="Club B" code="CLB" shortname="B" type="NATIONALTEAM"><CONTACT email="club-b@example.com"/><ATHLETES><ATHLETE athleteid="1" firstname="John" lastname="Smith" gender="M" birthdate="2011-01-01"><ENTRIES><ENTRY eventid="2"/></ENTRIES></ATHLETE></ATHLETES><RELAYS><RELAY number="1" gender="M"><ENTRIES><ENTRY eventid="3"><RELAYPOSITIONS><RELAYPOSITION athleteid="1" number="1"/></RELAYPOSITIONS></ENTRY></ENTRIES></RELAY></RELAYS></CLUB>"#,
+        );
+
+        let conflicts = lenex.merge_entries(other);
+        assert!(conflicts.is_empty());
+
+        let clubs = lenex.meets[0].clubs();
+        assert_eq!(2, clubs.len());
+        assert_eq!(2, clubs[1].id());
+        assert_eq!(Some("B"), clubs[1].short_name());
+        assert_eq!(Some(&ClubType::NationalTeam), clubs[1].r#type());
+        assert_eq!(
+            Some("club-b@example.com"),
+            clubs[1]
+                .contact()
+                .and_then(|contact| contact.email.as_deref())
+        );
+        assert_eq!(2, clubs[1].athletes()[0].id());
+        assert_eq!(2, clubs[1].athletes()[0].entries()[0].event_id());
+
+        let relay_entry = &clubs[1].relays()[0].entries()[0];
+        assert_eq!(3, relay_entry.event_id());
+        assert_eq!(Some(2), relay_entry.relay_positions()[0].athlete_id);
+    }
+
+    #[test]
+    fn merge_existing_athlete() {
+        let mut lenex = meet_file(
+            r#"<CLUB clubid="1" name="Club A" nation="CAN"><ATHLETES><ATHLETE athleteid="1" firstname="Jane" lastname="Doe" gender="F" birthdate="2010-01-01"><ENTRIES><ENTRY eventid="1" entrytime="00:00:31.00"/></ENTRIES></ATHLETE></ATHLETES></CLUB>"#,
+        );
+        let other = Lenex::try_from(
+            r#"<LENEX version="3.0"><CONSTRUCTOR name="lenex-rs" registration="lenex-rs" version="0.1.0"><CONTACT name="lenex-rs" country="CA" email="lenex@example.com" internet="https://github.com/francisbr/lenex-rs"/></CONSTRUCTOR><MEETS><MEET name="Meet" city="Montreal" nation="CAN"><SESSIONS><SESSION date="2023-02-11" number="1"><EVENTS><EVENT eventid="11" number="1"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/></EVENT><EVENT eventid="12" number="2"><SWIMSTYLE swimstyleid="2" distance="100" relaycount="1" stroke="BACK"/></EVENT><EVENT eventid="14" number="4"><SWIMSTYLE swimstyleid="4" distance="200" relaycount="1" stroke="FLY"/></EVENT></EVENTS></SESSION></SESSIONS><CLUBS><CLUB clubid="7" name="Club A" nation="CAN"><ATHLETES><ATHLETE athleteid="9" firstname="Jane" lastname="Doe" gender="F" birthdate="2010-01-01"><ENTRIES><ENTRY eventid="11" entrytime="00:00:30.00"/><ENTRY eventid="12"/><ENTRY eventid="14"/></ENTRIES></ATHLETE></ATHLETES></CLUB></CLUBS></MEET></MEETS></LENEX>"#
+                .to_string(),
+        )
+        .unwrap();
+
+        let conflicts = lenex.merge_entries(other);
+        assert_eq!(
+            vec![
+                MergeConflict::EventNotFound {
+                    event_id: 14,
+                    number: 4
+                },
+                MergeConflict::Entry {
+                    club: "Club A".into(),
+                    competitor: "Jane Doe".into(),
+                    event_id: 1
+                },
+            ],
+            conflicts
+        );
+
+        let clubs = lenex.meets[0].clubs();
+        assert_eq!(1, clubs.len());
+
+        let entries = clubs[0].athletes()[0].entries();
+        assert_eq!(2, entries.len());
+        assert_eq!(Some(SwimTime::Time(3100)), entries[0].entry_time());
+        assert_eq!(2, entries[1].event_id());
+    }
+
+    #[test]
+    fn merge_mismatched_relay_athlete() {
+        let mut lenex = meet_file(
+            r#"<CLUB clubid="1" name="Club A" nation="CAN"><ATHLETES><ATHLETE athleteid="1" firstname="Jane" lastname="Doe" gender="F" license="L1" birthdate="2010-01-01"/></ATHLETES></CLUB>"#,
+        );
+        let other = meet_file(
+            r#"<CLUB clubid="1" name="Club A" nation="CAN"><ATHLETES><ATHLETE athleteid="1" firstname="Mary" lastname="Major" gender="F" license="L1" birthdate="2010-05-05"/><ATHLETE athleteid="3" firstname="Ann" lastname="Lee" gender="F" birthdate="2010-06-06"/></ATHLETES><RELAYS><RELAY number="1" gender="F"><ENTRIES><ENTRY eventid="3"><RELAYPOSITIONS><RELAYPOSITION athleteid="1" number="1"/><RELAYPOSITION athleteid="3" number="2"/></RELAYPOSITIONS></ENTRY></ENTRIES></RELAY></RELAYS></CLUB>"#,
+        );
+
+        let conflicts = lenex.merge_entries(other);
+        assert_eq!(
+            vec![MergeConflict::AthleteMismatch {
+                club: "Club A".into(),
+                license: "L1".into()
+            }],
+            conflicts
+        );
+
+        let club = &lenex.meets[0].clubs()[0];
+        assert_eq!(2, club.athletes().len());
+
+        let positions = club.relays()[0].entries()[0].relay_positions();
+        assert_eq!(None, positions[0].athlete_id);
+        assert_eq!(Some(3), positions[1].athlete_id);
+    }
+
+    #[test]
+    fn merge_event_without_gender() {
+        let mut lenex = meet_file(
+            r#"<CLUB clubid="1" name="Club A" nation="CAN"><ATHLETES><ATHLETE athleteid="1" firstname="Jane" lastname="Doe" gender="F" birthdate="2010-01-01"/></ATHLETES></CLUB>"#,
+        );
+        lenex.meets[0].sessions_mut()[0].events_mut()[0].with_gender(Gender::Female);
+        let other = meet_file(
+            r#"<CLUB clubid="1" name="Club A" nation="CAN"><ATHLETES><ATHLETE athleteid="1" firstname="Jane" lastname="Doe" gender="F" birthdate="2010-01-01"><ENTRIES><ENTRY eventid="1"/></ENTRIES></ATHLETE></ATHLETES></CLUB>"#,
+        );
+
+        let conflicts = lenex.merge_entries(other);
+        assert!(conflicts.is_empty());
+
+        let events = lenex.meets[0].sessions()[0].events();
+        assert_eq!(3, events.len());
+        let entries = lenex.meets[0].clubs()[0].athletes()[0].entries();
+        assert_eq!(1, entries[0].event_id());
+    }
+
+    #[test]
+    fn merge_other_meet() {
+        let mut lenex = meet_file("");
+        let mut other = meet_file("");
+        other.meets[0] = Meet::new("Other".into(), "CAN".into(), "Quebec".into(), vec![]);
+
+        let conflicts = lenex.merge_entries(other);
+        assert_eq!(
+            vec![MergeConflict::MeetNotFound {
+                name: "Other".into(),
+                city: "Quebec".into()
+            }],
+            conflicts
+        );
+        assert_eq!("meet Other in Quebec not found", conflicts[0].to_string());
+    }
+}
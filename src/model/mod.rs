@@ -1,58 +1,173 @@
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
+use crate::serialization::preserve::Preserve;
+
 mod age_date;
 mod age_group;
 mod athlete;
+mod birth_date;
 mod club;
+mod contact;
 mod course;
+mod diff;
+mod entries;
 mod entry;
 mod event;
+mod extra;
 mod fee;
+mod fee_calculator;
 mod gender;
+mod handicap;
+mod heat;
+mod index;
+mod judge;
 mod lenex;
 mod meet;
+mod merge;
+mod official;
 mod pool;
+mod record;
+mod record_holder;
+mod record_list;
+mod relay;
+mod relay_position;
+mod result;
 mod round;
 mod session;
+mod split;
+mod start_list;
 mod stroke;
+mod swim_time;
 mod swimstyle;
+mod time_standard;
+mod time_standard_list;
+mod time_standard_ref;
 mod timing;
+mod validation;
 
 pub use age_date::*;
 pub use age_group::*;
 pub use athlete::*;
+pub use birth_date::*;
 pub use club::*;
+pub use contact::*;
 pub use course::*;
+pub use diff::*;
+pub use entries::*;
 pub use entry::*;
 pub use event::*;
+pub use extra::*;
 pub use fee::*;
+pub use fee_calculator::*;
 pub use gender::*;
+pub use handicap::*;
+pub use heat::*;
+pub use index::*;
+pub use judge::*;
 pub use lenex::*;
 pub use meet::*;
+pub use merge::*;
+pub use official::*;
 pub use pool::*;
+pub use record::*;
+pub use record_holder::*;
+pub use record_list::*;
+pub use relay::*;
+pub use relay_position::*;
+pub use result::*;
 pub use round::*;
 pub use session::*;
+pub use split::*;
+pub use start_list::*;
 pub use stroke::*;
+pub use swim_time::*;
 pub use swimstyle::*;
+pub use time_standard::*;
+pub use time_standard_list::*;
+pub use time_standard_ref::*;
 pub use timing::*;
+pub use validation::*;
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+pub struct Bank {
+    #[serde(rename = "accountholder")]
+    pub account_holder: Option<String>,
+    pub bic: Option<String>,
+    pub iban: Option<String>,
+    pub name: Option<String>,
+    pub number: Option<String>,
+    pub comment: Option<String>,
 
-#[derive(Serialize, Deserialize, PartialEq, Default, Debug)]
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
 pub struct Facility {
     pub city: String,
     pub name: String,
     pub nation: String,
+
+    #[serde(skip)]
+    pub extra: Extra,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Default, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
 pub struct PointTable {
     #[serde(rename = "pointtableid")]
     pub id: u32,
     pub name: String,
     pub version: String,
+
+    #[serde(skip)]
+    pub extra: Extra,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Default, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
 pub struct Qualify {
     pub from: NaiveDate,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+impl Preserve for Bank {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+}
+
+impl Preserve for Facility {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+}
+
+impl Preserve for PointTable {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+}
+
+impl Preserve for Qualify {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
 }
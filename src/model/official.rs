@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use crate::serialization::preserve::{preserve_child, restore_child, Node, Preserve};
+
+use super::{contact::Contact, extra::Extra, gender::Gender};
+
+/// A technical official of a meet, assigned to sessions through [`Judge`](super::Judge)s.
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[serde(rename = "OFFICIAL")]
+pub struct Official {
+    #[serde(rename = "officialid")]
+    pub id: u32,
+
+    #[serde(rename = "firstname")]
+    pub first_name: String,
+
+    #[serde(rename = "lastname")]
+    pub last_name: String,
+
+    pub gender: Option<Gender>,
+
+    pub grade: Option<String>,
+
+    pub license: Option<String>,
+
+    pub nation: Option<String>,
+
+    #[serde(rename = "CONTACT")]
+    pub contact: Option<Contact>,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+impl Official {
+    pub fn new(id: u32, first_name: String, last_name: String) -> Self {
+        Self {
+            id,
+            first_name,
+            last_name,
+            ..Default::default()
+        }
+    }
+}
+
+impl Preserve for Official {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_child(self.contact.as_mut(), node, "CONTACT");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_child(self.contact.as_ref(), node, "CONTACT");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fast_xml::{de, se};
+
+    use super::*;
+
+    #[test]
+    fn deserialize_basic() {
+        let result = de::from_str::<Official>(
+            r#"<OFFICIAL officialid="3" firstname="Marie" lastname="Tremblay" gender="F" grade="NAT" license="QC-42" nation="CAN"><CONTACT email="marie@example.com"/></OFFICIAL>"#,
+        );
+        assert!(result.is_ok());
+
+        let official = result.unwrap();
+        assert_eq!(3, official.id);
+        assert_eq!("Tremblay", official.last_name);
+        assert_eq!(Some(Gender::Female), official.gender);
+        assert_eq!(Some("NAT".into()), official.grade);
+        assert_eq!(
+            Some("marie@example.com"),
+            official.contact.unwrap().email.as_deref()
+        );
+    }
+
+    #[test]
+    fn serialize_built() {
+        let mut official = Official::new(3, "Marie".into(), "Tremblay".into());
+        official.nation = Some("CAN".into());
+
+        let result = se::to_string(&official);
+        assert!(result.is_ok());
+        assert_eq!(
+            r#"<OFFICIAL officialid="3" firstname="Marie" lastname="Tremblay" nation="CAN"/>"#,
+            result.unwrap()
+        );
+    }
+}
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, PartialEq, Default, Debug)]
+use crate::serialization::preserve::Preserve;
+
+use super::extra::Extra;
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
 pub struct Pool {
     pub name: Option<String>,
 
@@ -9,6 +13,9 @@ pub struct Pool {
 
     #[serde(rename = "lanemax")]
     pub lane_max: Option<u32>,
+
+    #[serde(skip)]
+    pub extra: Extra,
 }
 
 impl Pool {
@@ -20,3 +27,13 @@ impl Pool {
         None
     }
 }
+
+impl Preserve for Pool {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+}
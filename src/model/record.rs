@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    collection::Collection,
+    serialization::preserve::{
+        preserve_child, preserve_collection, restore_child, restore_collection, Node, Preserve,
+    },
+};
+
+use super::{
+    extra::Extra, record_holder::RecordHolder, relay::Relay, split::Split, swim_time::SwimTime,
+    swimstyle::SwimStyle,
+};
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[serde(rename = "RECORD")]
+pub struct Record {
+    #[serde(rename = "swimtime", default)]
+    pub swim_time: Option<SwimTime>,
+
+    pub status: Option<String>,
+
+    pub comment: Option<String>,
+
+    #[serde(rename = "SWIMSTYLE")]
+    pub swim_style: SwimStyle,
+
+    #[serde(rename = "ATHLETE")]
+    pub athlete: Option<RecordHolder>,
+
+    #[serde(rename = "RELAY")]
+    pub relay: Option<Relay>,
+
+    #[serde(rename = "SPLITS", default, skip_serializing_if = "Vec::is_empty")]
+    pub splits: Collection<Split>,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+impl Record {
+    pub fn new(swim_style: SwimStyle, swim_time: SwimTime) -> Self {
+        Self {
+            swim_style,
+            swim_time: Some(swim_time),
+            ..Default::default()
+        }
+    }
+}
+
+impl Preserve for Record {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_child(Some(&mut self.swim_style), node, "SWIMSTYLE");
+        preserve_child(self.athlete.as_mut(), node, "ATHLETE");
+        preserve_child(self.relay.as_mut(), node, "RELAY");
+        preserve_collection(&mut self.splits, node, "SPLITS");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_child(Some(&self.swim_style), node, "SWIMSTYLE");
+        restore_child(self.athlete.as_ref(), node, "ATHLETE");
+        restore_child(self.relay.as_ref(), node, "RELAY");
+        restore_collection(&self.splits, node, "SPLITS");
+    }
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+use crate::serialization::preserve::Preserve;
+
+use super::{birth_date::BirthDate, extra::Extra, gender::Gender};
+
+/// The athlete holding a record, or one of the swimmers of a record relay.
+///
+/// Unlike the athletes of a meet, record holders are usually not given an id.
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[serde(rename = "ATHLETE")]
+pub struct RecordHolder {
+    #[serde(rename = "athleteid")]
+    pub id: Option<u32>,
+
+    #[serde(rename = "firstname")]
+    pub first_name: String,
+
+    #[serde(rename = "lastname")]
+    pub last_name: String,
+
+    pub gender: Gender,
+
+    pub license: Option<String>,
+
+    pub nation: Option<String>,
+
+    pub swrid: Option<u32>,
+
+    #[serde(rename = "birthdate", default)]
+    pub birth_date: Option<BirthDate>,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+impl RecordHolder {
+    pub fn new(first_name: String, last_name: String, gender: Gender) -> Self {
+        Self {
+            first_name,
+            last_name,
+            gender,
+            ..Default::default()
+        }
+    }
+}
+
+impl Preserve for RecordHolder {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+}
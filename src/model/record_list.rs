@@ -0,0 +1,162 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    collection::Collection,
+    serialization::preserve::{
+        preserve_child, preserve_collection, restore_child, restore_collection, Node, Preserve,
+    },
+};
+
+use super::{age_group::AgeGroup, course::Course, extra::Extra, gender::Gender, record::Record};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename = "RECORDLIST")]
+pub struct RecordList {
+    pub course: Course,
+
+    pub gender: Gender,
+
+    pub name: String,
+
+    pub nation: Option<String>,
+
+    pub region: Option<String>,
+
+    pub r#type: Option<String>,
+
+    pub updated: Option<NaiveDate>,
+
+    #[serde(default, with = "crate::serialization::serde_number")]
+    pub order: Option<u32>,
+
+    #[serde(rename = "AGEGROUP")]
+    pub age_group: Option<AgeGroup>,
+
+    #[serde(rename = "RECORDS")]
+    pub records: Collection<Record>,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+impl RecordList {
+    pub fn new(course: Course, gender: Gender, name: String) -> Self {
+        Self {
+            course,
+            gender,
+            name,
+            nation: None,
+            region: None,
+            r#type: None,
+            updated: None,
+            order: None,
+            age_group: None,
+            records: Vec::new().into(),
+            extra: Extra::default(),
+        }
+    }
+
+    /// Whether this list holds the records of a swimmer of `age`.
+    ///
+    /// Open lists, that is lists without an age group, cover everyone, while lists with one
+    /// only cover a known age it includes.
+    pub fn covers_age(&self, age: Option<u16>) -> bool {
+        self.age_group
+            .as_ref()
+            .is_none_or(|age_group| age.is_some_and(|age| age_group.includes_age(age)))
+    }
+}
+
+impl Preserve for RecordList {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_child(self.age_group.as_mut(), node, "AGEGROUP");
+        preserve_collection(&mut self.records, node, "RECORDS");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_child(self.age_group.as_ref(), node, "AGEGROUP");
+        restore_collection(&self.records, node, "RECORDS");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fast_xml::{de, se};
+
+    use crate::model::SwimTime;
+
+    use crate::model::{Stroke, SwimStyle};
+
+    use super::*;
+
+    #[test]
+    fn deserialize_empty() {
+        let result = de::from_str::<RecordList>(r#"<RECORDLIST/>"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deserialize_records() {
+        let result = de::from_str::<RecordList>(
+            r#"<RECORDLIST course="LCM" gender="F" name="Provincial records" nation="CAN" region="QC" type="PROV" updated="2023-08-01"><AGEGROUP agegroupid="1" agemin="13" agemax="14"/><RECORDS><RECORD swimtime="00:00:26.12"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/><ATHLETE firstname="Jane" lastname="Doe" gender="F" birthdate="2008-05-02"/><SPLITS><SPLIT distance="25" swimtime="00:00:12.50"/></SPLITS></RECORD><RECORD swimtime="00:01:58.40"><SWIMSTYLE swimstyleid="2" distance="50" relaycount="4" stroke="FREE"/><RELAY number="1" gender="F" name="Club"><RELAYPOSITIONS><RELAYPOSITION number="1"><ATHLETE firstname="A" lastname="A" gender="F" birthdate="2009-01-01"/></RELAYPOSITION></RELAYPOSITIONS></RELAY></RECORD></RECORDS></RECORDLIST>"#,
+        );
+        assert!(result.is_ok());
+
+        let record_list = result.unwrap();
+        assert_eq!(Course::LCM, record_list.course);
+        assert_eq!(Gender::Female, record_list.gender);
+        assert_eq!(Some("PROV".into()), record_list.r#type);
+        assert!(record_list.updated.is_some());
+        assert_eq!(Some(13), record_list.age_group.as_ref().unwrap().age_min);
+        assert_eq!(2, record_list.records.len());
+
+        let individual = record_list.records.first().unwrap();
+        assert_eq!(Some(SwimTime::Time(2612)), individual.swim_time);
+        let holder = individual.athlete.as_ref().unwrap();
+        assert_eq!(None, holder.id);
+        assert_eq!("Doe", holder.last_name);
+        assert_eq!(1, individual.splits.len());
+
+        let relay = record_list.records.last().unwrap().relay.as_ref().unwrap();
+        let position = relay.relay_positions().first().unwrap();
+        assert!(position.athlete_id.is_none());
+        assert!(position.athlete.is_some());
+    }
+
+    #[test]
+    fn covers_age() {
+        let mut record_list = RecordList::new(Course::SCM, Gender::Male, "Records".into());
+        assert!(record_list.covers_age(None));
+        assert!(record_list.covers_age(Some(12)));
+
+        record_list.age_group = Some(AgeGroup::new(1, Some(11), Some(12)));
+        assert!(!record_list.covers_age(None));
+        assert!(record_list.covers_age(Some(12)));
+        assert!(!record_list.covers_age(Some(13)));
+    }
+
+    #[test]
+    fn serialize() {
+        let mut record_list = RecordList::new(Course::SCM, Gender::Male, "Records".into());
+        record_list.records.push(Record::new(
+            SwimStyle::new(1, 50, 1, Stroke::Unknown),
+            SwimTime::Time(2100),
+        ));
+
+        let result = se::to_string(&record_list);
+        assert!(result.is_ok());
+        assert_eq!(
+            r#"<RECORDLIST course="SCM" gender="M" name="Records"><RECORDS><RECORD swimtime="00:00:21.00"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="UNKNOWN"/></RECORD></RECORDS></RECORDLIST>"#,
+            result.unwrap()
+        );
+    }
+}
@@ -0,0 +1,261 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    collection::Collection,
+    serialization::preserve::{preserve_collection, restore_collection, Node, Preserve},
+};
+
+use super::{
+    entry::Entry, extra::Extra, gender::Gender, relay_position::RelayPosition, result::Result,
+};
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[serde(rename = "RELAY")]
+pub struct Relay {
+    number: Option<u32>,
+
+    gender: Gender,
+
+    #[serde(rename = "agemin", default, with = "crate::serialization::serde_age")]
+    age_min: Option<u16>,
+
+    #[serde(rename = "agemax", default, with = "crate::serialization::serde_age")]
+    age_max: Option<u16>,
+
+    #[serde(
+        rename = "agetotalmin",
+        default,
+        with = "crate::serialization::serde_number"
+    )]
+    age_total_min: Option<u32>,
+
+    #[serde(
+        rename = "agetotalmax",
+        default,
+        with = "crate::serialization::serde_number"
+    )]
+    age_total_max: Option<u32>,
+
+    name: Option<String>,
+
+    #[serde(rename = "ENTRIES", default, skip_serializing_if = "Vec::is_empty")]
+    entries: Collection<Entry>,
+
+    #[serde(rename = "RESULTS", default, skip_serializing_if = "Vec::is_empty")]
+    results: Collection<Result>,
+
+    #[serde(
+        rename = "RELAYPOSITIONS",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    relay_positions: Collection<RelayPosition>,
+
+    #[serde(skip)]
+    extra: Extra,
+}
+
+impl Relay {
+    pub fn new(number: u32, gender: Gender) -> Self {
+        Self {
+            number: Some(number),
+            gender,
+            ..Default::default()
+        }
+    }
+
+    pub fn number(&self) -> Option<u32> {
+        self.number
+    }
+
+    pub fn gender(&self) -> &Gender {
+        &self.gender
+    }
+
+    pub fn age_min(&self) -> Option<u16> {
+        self.age_min
+    }
+
+    pub fn age_max(&self) -> Option<u16> {
+        self.age_max
+    }
+
+    pub fn age_total_min(&self) -> Option<u32> {
+        self.age_total_min
+    }
+
+    pub fn age_total_max(&self) -> Option<u32> {
+        self.age_total_max
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn entries_mut(&mut self) -> &mut Vec<Entry> {
+        &mut self.entries
+    }
+
+    pub fn results(&self) -> &[Result] {
+        &self.results
+    }
+
+    pub fn results_mut(&mut self) -> &mut Vec<Result> {
+        &mut self.results
+    }
+
+    pub fn relay_positions(&self) -> &[RelayPosition] {
+        &self.relay_positions
+    }
+
+    pub fn relay_positions_mut(&mut self) -> &mut Vec<RelayPosition> {
+        &mut self.relay_positions
+    }
+
+    pub fn with_age_min(&mut self, age_min: u16) -> &mut Self {
+        self.age_min = Some(age_min);
+
+        self
+    }
+
+    pub fn with_age_max(&mut self, age_max: u16) -> &mut Self {
+        self.age_max = Some(age_max);
+
+        self
+    }
+
+    pub fn with_age_total_min(&mut self, age_total_min: u32) -> &mut Self {
+        self.age_total_min = Some(age_total_min);
+
+        self
+    }
+
+    pub fn with_age_total_max(&mut self, age_total_max: u32) -> &mut Self {
+        self.age_total_max = Some(age_total_max);
+
+        self
+    }
+
+    pub fn with_name(&mut self, name: String) -> &mut Self {
+        self.name = Some(name);
+
+        self
+    }
+
+    pub fn add_entry(&mut self, entry: Entry) -> &mut Self {
+        self.entries.push(entry);
+
+        self
+    }
+
+    pub fn add_result(&mut self, result: Result) -> &mut Self {
+        self.results.push(result);
+
+        self
+    }
+
+    pub fn add_relay_position(&mut self, relay_position: RelayPosition) -> &mut Self {
+        self.relay_positions.push(relay_position);
+
+        self
+    }
+
+    /// The attributes and child elements of this element unknown to the model.
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
+}
+
+impl Preserve for Relay {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_collection(&mut self.entries, node, "ENTRIES");
+        preserve_collection(&mut self.results, node, "RESULTS");
+        preserve_collection(&mut self.relay_positions, node, "RELAYPOSITIONS");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_collection(&self.entries, node, "ENTRIES");
+        restore_collection(&self.results, node, "RESULTS");
+        restore_collection(&self.relay_positions, node, "RELAYPOSITIONS");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fast_xml::{de, se};
+
+    use crate::model::ResultStatus;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_empty() {
+        let result = de::from_str::<Relay>(r#"<RELAY/>"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deserialize_basic() {
+        let result = de::from_str::<Relay>(
+            r#"<RELAY number="1" gender="X" agemin="-1" agemax="-1" agetotalmin="100" agetotalmax="119" name="Club A"/>"#,
+        );
+        assert!(result.is_ok());
+
+        let relay = result.unwrap();
+        assert_eq!(Some(1), relay.number());
+        assert_eq!(Gender::Mixed, *relay.gender());
+        assert!(relay.age_min().is_none());
+        assert!(relay.age_max().is_none());
+        assert_eq!(Some(100), relay.age_total_min());
+        assert_eq!(Some(119), relay.age_total_max());
+        assert_eq!(Some("Club A"), relay.name());
+        assert!(relay.entries().is_empty());
+        assert!(relay.results().is_empty());
+    }
+
+    #[test]
+    fn deserialize_entries_and_results() {
+        let result = de::from_str::<Relay>(
+            r#"<RELAY number="1" gender="F"><ENTRIES><ENTRY eventid="12" entrytime="00:02:01.50"><RELAYPOSITIONS><RELAYPOSITION athleteid="1" number="1"/><RELAYPOSITION athleteid="2" number="2"/></RELAYPOSITIONS></ENTRY></ENTRIES><RESULTS><RESULT resultid="5" eventid="12" swimtime="00:01:59.98"><RELAYPOSITIONS><RELAYPOSITION athleteid="1" number="1" reactiontime="+64"/><RELAYPOSITION athleteid="2" number="2" reactiontime="+21" status="DSQ"/></RELAYPOSITIONS></RESULT></RESULTS></RELAY>"#,
+        );
+        assert!(result.is_ok());
+
+        let relay = result.unwrap();
+        assert_eq!(1, relay.entries().len());
+        assert_eq!(2, relay.entries().first().unwrap().relay_positions().len());
+
+        assert_eq!(1, relay.results().len());
+        let positions = relay.results().first().unwrap().relay_positions();
+        assert_eq!(2, positions.len());
+        assert_eq!(Some(2), positions.last().unwrap().athlete_id);
+        assert_eq!(
+            Some(ResultStatus::Disqualified),
+            positions.last().unwrap().status
+        );
+    }
+
+    #[test]
+    fn serialize() {
+        let mut relay = Relay::new(2, Gender::Male);
+        relay.with_age_min(15).with_name("Club B".into());
+
+        let result = se::to_string(&relay);
+        assert!(result.is_ok());
+        assert_eq!(
+            r#"<RELAY number="2" gender="M" agemin="15" agemax="-1" name="Club B"/>"#,
+            result.unwrap()
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+use crate::serialization::preserve::{preserve_child, restore_child, Node, Preserve};
+
+use super::{extra::Extra, record_holder::RecordHolder, result::ResultStatus};
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[serde(rename = "RELAYPOSITION")]
+pub struct RelayPosition {
+    #[serde(rename = "athleteid")]
+    pub athlete_id: Option<u32>,
+
+    pub number: u32,
+
+    #[serde(
+        rename = "reactiontime",
+        default,
+        with = "crate::serialization::serde_reaction_time"
+    )]
+    pub reaction_time: Option<Duration>,
+
+    pub status: Option<ResultStatus>,
+
+    /// The swimmer of a record relay, whose athletes are not listed by any club.
+    #[serde(rename = "ATHLETE")]
+    pub athlete: Option<RecordHolder>,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+impl RelayPosition {
+    pub fn new(athlete_id: u32, number: u32) -> Self {
+        Self {
+            athlete_id: Some(athlete_id),
+            number,
+            ..Default::default()
+        }
+    }
+}
+
+impl Preserve for RelayPosition {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_child(self.athlete.as_mut(), node, "ATHLETE");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_child(self.athlete.as_ref(), node, "ATHLETE");
+    }
+}
@@ -0,0 +1,279 @@
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    collection::Collection,
+    serialization::preserve::{preserve_collection, restore_collection, Node, Preserve},
+};
+
+pub use self::status::ResultStatus;
+
+use super::{extra::Extra, relay_position::RelayPosition, split::Split, swim_time::SwimTime};
+
+mod status;
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[serde(rename = "RESULT")]
+pub struct Result {
+    #[serde(rename = "resultid")]
+    id: u32,
+
+    #[serde(rename = "eventid")]
+    event_id: u32,
+
+    #[serde(rename = "swimtime", default)]
+    swim_time: Option<SwimTime>,
+
+    status: Option<ResultStatus>,
+
+    points: Option<u32>,
+
+    lane: Option<u32>,
+
+    #[serde(rename = "heatid")]
+    heat_id: Option<u32>,
+
+    comment: Option<String>,
+
+    #[serde(
+        rename = "reactiontime",
+        default,
+        with = "crate::serialization::serde_reaction_time"
+    )]
+    reaction_time: Option<Duration>,
+
+    #[serde(rename = "SPLITS", default, skip_serializing_if = "Vec::is_empty")]
+    splits: Collection<Split>,
+
+    #[serde(
+        rename = "RELAYPOSITIONS",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    relay_positions: Collection<RelayPosition>,
+
+    #[serde(skip)]
+    extra: Extra,
+}
+
+impl Result {
+    pub fn new(id: u32, event_id: u32) -> Self {
+        Self {
+            id,
+            event_id,
+            ..Default::default()
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn event_id(&self) -> u32 {
+        self.event_id
+    }
+
+    pub fn swim_time(&self) -> Option<SwimTime> {
+        self.swim_time
+    }
+
+    pub fn status(&self) -> Option<&ResultStatus> {
+        self.status.as_ref()
+    }
+
+    pub fn points(&self) -> Option<u32> {
+        self.points
+    }
+
+    pub fn lane(&self) -> Option<u32> {
+        self.lane
+    }
+
+    pub fn heat_id(&self) -> Option<u32> {
+        self.heat_id
+    }
+
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    pub fn reaction_time(&self) -> Option<Duration> {
+        self.reaction_time
+    }
+
+    pub fn splits(&self) -> &[Split] {
+        &self.splits
+    }
+
+    pub fn splits_mut(&mut self) -> &mut Vec<Split> {
+        &mut self.splits
+    }
+
+    pub fn relay_positions(&self) -> &[RelayPosition] {
+        &self.relay_positions
+    }
+
+    pub fn relay_positions_mut(&mut self) -> &mut Vec<RelayPosition> {
+        &mut self.relay_positions
+    }
+
+    pub fn with_swim_time(&mut self, swim_time: SwimTime) -> &mut Self {
+        self.swim_time = Some(swim_time);
+
+        self
+    }
+
+    pub fn with_status(&mut self, status: ResultStatus) -> &mut Self {
+        self.status = Some(status);
+
+        self
+    }
+
+    pub fn with_points(&mut self, points: u32) -> &mut Self {
+        self.points = Some(points);
+
+        self
+    }
+
+    pub fn with_lane(&mut self, lane: u32) -> &mut Self {
+        self.lane = Some(lane);
+
+        self
+    }
+
+    pub fn with_heat_id(&mut self, heat_id: u32) -> &mut Self {
+        self.heat_id = Some(heat_id);
+
+        self
+    }
+
+    pub fn with_comment(&mut self, comment: String) -> &mut Self {
+        self.comment = Some(comment);
+
+        self
+    }
+
+    pub fn with_reaction_time(&mut self, reaction_time: Duration) -> &mut Self {
+        self.reaction_time = Some(reaction_time);
+
+        self
+    }
+
+    pub fn add_split(&mut self, split: Split) -> &mut Self {
+        self.splits.push(split);
+
+        self
+    }
+
+    pub fn add_relay_position(&mut self, relay_position: RelayPosition) -> &mut Self {
+        self.relay_positions.push(relay_position);
+
+        self
+    }
+
+    /// The attributes and child elements of this element unknown to the model.
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
+}
+
+impl Preserve for Result {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_collection(&mut self.splits, node, "SPLITS");
+        preserve_collection(&mut self.relay_positions, node, "RELAYPOSITIONS");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_collection(&self.splits, node, "SPLITS");
+        restore_collection(&self.relay_positions, node, "RELAYPOSITIONS");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fast_xml::{de, se};
+
+    use super::*;
+
+    #[test]
+    fn deserialize_empty() {
+        let result = de::from_str::<Result>(r#"<RESULT/>"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deserialize_basic() {
+        let result = de::from_str::<Result>(
+            r#"<RESULT resultid="12" eventid="3" swimtime="00:01:02.34" points="512" lane="4" heatid="30" reactiontime="+71"/>"#,
+        );
+        assert!(result.is_ok());
+
+        let result = result.unwrap();
+        assert_eq!(12, result.id());
+        assert_eq!(3, result.event_id());
+        assert_eq!(Some(SwimTime::Time(6234)), result.swim_time());
+        assert_eq!(Some(512), result.points());
+        assert_eq!(Some(4), result.lane());
+        assert_eq!(Some(30), result.heat_id());
+        assert_eq!(710, result.reaction_time().unwrap().num_milliseconds());
+        assert!(result.status().is_none());
+        assert!(result.splits().is_empty());
+    }
+
+    #[test]
+    fn deserialize_status() {
+        let result = de::from_str::<Result>(
+            r#"<RESULT resultid="12" eventid="3" status="DSQ" comment="False start" reactiontime="-5"/>"#,
+        );
+        assert!(result.is_ok());
+
+        let result = result.unwrap();
+        assert_eq!(Some(&ResultStatus::Disqualified), result.status());
+        assert_eq!(Some("False start"), result.comment());
+        assert_eq!(-50, result.reaction_time().unwrap().num_milliseconds());
+        assert!(result.swim_time().is_none());
+    }
+
+    #[test]
+    fn deserialize_splits() {
+        let result = de::from_str::<Result>(
+            r#"<RESULT resultid="12" eventid="3" swimtime="00:01:02.34"><SPLITS><SPLIT distance="50" swimtime="00:00:29.87"/><SPLIT distance="75" swimtime="00:00:46.1"/></SPLITS></RESULT>"#,
+        );
+        assert!(result.is_ok());
+
+        let result = result.unwrap();
+        assert_eq!(2, result.splits().len());
+
+        let last = result.splits().last().unwrap();
+        assert_eq!(75, last.distance);
+        assert_eq!(Some(SwimTime::Time(4610)), last.swim_time);
+    }
+
+    #[test]
+    fn serialize() {
+        let mut result = Result::new(12, 3);
+        result
+            .with_swim_time(SwimTime::Time(5834))
+            .with_reaction_time(Duration::milliseconds(710))
+            .add_split(Split {
+                distance: 50,
+                swim_time: Some(SwimTime::Time(2987)),
+                ..Default::default()
+            });
+
+        let xml = se::to_string(&result);
+        assert!(xml.is_ok());
+        assert_eq!(
+            r#"<RESULT resultid="12" eventid="3" swimtime="00:00:58.34" reactiontime="+71"><SPLITS><SPLIT distance="50" swimtime="00:00:29.87"/></SPLITS></RESULT>"#,
+            xml.unwrap()
+        );
+    }
+}
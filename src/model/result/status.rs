@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
+
+#[derive(Serialize, Deserialize, IntoStaticStr, PartialEq, Debug, Clone)]
+#[serde(into = "&str")]
+pub enum ResultStatus {
+    #[serde(rename(deserialize = "EXH"))]
+    #[strum(serialize = "EXH")]
+    Exhibition,
+
+    #[serde(rename(deserialize = "DSQ"))]
+    #[strum(serialize = "DSQ")]
+    Disqualified,
+
+    #[serde(rename(deserialize = "DNS"))]
+    #[strum(serialize = "DNS")]
+    DidNotStart,
+
+    #[serde(rename(deserialize = "DNF"))]
+    #[strum(serialize = "DNF")]
+    DidNotFinish,
+
+    #[serde(rename(deserialize = "SICK"))]
+    #[strum(serialize = "SICK")]
+    Sick,
+
+    #[serde(rename(deserialize = "WDR"))]
+    #[strum(serialize = "WDR")]
+    Withdrawn,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fast_xml::{de, se};
+
+    #[test]
+    fn serialize() {
+        let value = ResultStatus::Disqualified;
+        let result = se::to_string(&value);
+        assert!(result.is_ok());
+
+        assert_eq!("DSQ", result.unwrap());
+    }
+
+    #[test]
+    fn deserialize() {
+        let result = de::from_str::<ResultStatus>("WDR");
+        assert!(result.is_ok());
+
+        assert_eq!(ResultStatus::Withdrawn, result.unwrap());
+    }
+}
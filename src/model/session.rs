@@ -1,11 +1,17 @@
 use chrono::{NaiveDate, NaiveTime};
 use serde::{Deserialize, Serialize};
 
-use crate::{collection::Collection, serialization::serde_time};
+use crate::{
+    collection::Collection,
+    serialization::{
+        preserve::{preserve_collection, restore_collection, Node, Preserve},
+        serde_time,
+    },
+};
 
-use super::event::Event;
+use super::{event::Event, extra::Extra, judge::Judge};
 
-#[derive(Serialize, Deserialize, PartialEq, Default, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
 #[serde(rename = "SESSION")]
 pub struct Session {
     date: NaiveDate,
@@ -33,6 +39,12 @@ pub struct Session {
 
     #[serde(rename = "EVENTS")]
     events: Collection<Event>,
+
+    #[serde(rename = "JUDGES", default, skip_serializing_if = "Vec::is_empty")]
+    judges: Collection<Judge>,
+
+    #[serde(skip)]
+    extra: Extra,
 }
 
 impl Session {
@@ -45,6 +57,88 @@ impl Session {
         }
     }
 
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    pub fn day_time(&self) -> Option<NaiveTime> {
+        self.day_time
+    }
+
+    pub fn end_time(&self) -> Option<NaiveTime> {
+        self.end_time
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn team_leader_meeting(&self) -> Option<NaiveTime> {
+        self.team_leader_meeting
+    }
+
+    pub fn warmup_from(&self) -> Option<NaiveTime> {
+        self.warmup_from
+    }
+
+    pub fn warmup_until(&self) -> Option<NaiveTime> {
+        self.warmup_until
+    }
+
+    pub fn timing(&self) -> Option<&str> {
+        self.timing.as_deref()
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    pub fn events_mut(&mut self) -> &mut Vec<Event> {
+        &mut self.events
+    }
+
+    pub fn judges(&self) -> &[Judge] {
+        &self.judges
+    }
+
+    pub fn judges_mut(&mut self) -> &mut Vec<Judge> {
+        &mut self.judges
+    }
+
+    pub fn with_name(&mut self, name: String) -> &mut Self {
+        self.name = Some(name);
+
+        self
+    }
+
+    pub fn with_team_leader_meeting(&mut self, time: NaiveTime) -> &mut Self {
+        self.team_leader_meeting = Some(time);
+
+        self
+    }
+
+    pub fn with_timing(&mut self, timing: String) -> &mut Self {
+        self.timing = Some(timing);
+
+        self
+    }
+
+    pub fn add_event(&mut self, event: Event) -> &mut Self {
+        self.events.push(event);
+
+        self
+    }
+
+    pub fn add_judge(&mut self, judge: Judge) -> &mut Self {
+        self.judges.push(judge);
+
+        self
+    }
+
     pub fn with_day_time(&mut self, time: NaiveTime) -> &mut Self {
         self.day_time = Some(time);
 
@@ -68,7 +162,33 @@ impl Session {
 
         self
     }
+
+    /// The attributes and child elements of this element unknown to the model.
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
+
+impl Preserve for Session {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_collection(&mut self.events, node, "EVENTS");
+        preserve_collection(&mut self.judges, node, "JUDGES");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_collection(&self.events, node, "EVENTS");
+        restore_collection(&self.judges, node, "JUDGES");
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{Datelike, Timelike};
@@ -113,7 +233,7 @@ mod tests {
         let session = result.unwrap();
         assert_eq!(123, session.number);
         assert_eq!(11, session.date.day());
-        assert_eq!(02, session.date.month());
+        assert_eq!(2, session.date.month());
         assert_eq!(2023, session.date.year());
         assert_eq!(0, session.events.len());
     }
@@ -128,7 +248,7 @@ mod tests {
         let session = result.unwrap();
         assert_eq!(123, session.number);
         assert_eq!(11, session.date.day());
-        assert_eq!(02, session.date.month());
+        assert_eq!(2, session.date.month());
         assert_eq!(2023, session.date.year());
         assert_eq!(0, session.events.len());
 
@@ -165,7 +285,7 @@ mod tests {
         assert!(session.name.is_some());
         assert_eq!("test session", session.name.unwrap());
         assert_eq!(1, session.events.len());
-        assert_eq!(1176, session.events.get(0).unwrap().id)
+        assert_eq!(1176, session.events.first().unwrap().id)
     }
 
     #[test]
@@ -181,6 +301,8 @@ mod tests {
             warmup_until: None,
             timing: None,
             events: Vec::new().into(),
+            judges: Vec::new().into(),
+            extra: Extra::default(),
         };
 
         let result = se::to_string(&session);
@@ -207,6 +329,8 @@ mod tests {
                 warmup_until: None,
                 timing: None,
                 events: Vec::new().into(),
+                judges: Vec::new().into(),
+                extra: Extra::default(),
             },
             Session {
                 date: NaiveDate::default(),
@@ -219,6 +343,8 @@ mod tests {
                 warmup_until: None,
                 timing: None,
                 events: Vec::new().into(),
+                judges: Vec::new().into(),
+                extra: Extra::default(),
             },
         ];
 
@@ -256,6 +382,8 @@ mod tests {
             warmup_until: None,
             timing: None,
             events: events.into(),
+            judges: Vec::new().into(),
+            extra: Extra::default(),
         };
 
         let result = se::to_string(&session);
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::serialization::preserve::Preserve;
+
+use super::{extra::Extra, swim_time::SwimTime};
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[serde(rename = "SPLIT")]
+pub struct Split {
+    pub distance: u32,
+
+    #[serde(rename = "swimtime", default)]
+    pub swim_time: Option<SwimTime>,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+impl Split {
+    pub fn new(distance: u32, swim_time: SwimTime) -> Self {
+        Self {
+            distance,
+            swim_time: Some(swim_time),
+            extra: Extra::default(),
+        }
+    }
+}
+
+impl Preserve for Split {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+}
@@ -0,0 +1,28 @@
+use super::{athlete::Athlete, club::Club, entry::Entry, relay::Relay, swim_time::SwimTime};
+
+/// Who swims in a lane of a heat.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Competitor<'a> {
+    Athlete(&'a Athlete),
+    Relay(&'a Relay),
+}
+
+/// One lane of a heat start list, as returned by [`Meet::start_list`](super::Meet::start_list).
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct StartListEntry<'a> {
+    pub lane: Option<u32>,
+    pub competitor: Competitor<'a>,
+    pub club: &'a Club,
+    pub entry_time: Option<SwimTime>,
+}
+
+impl<'a> StartListEntry<'a> {
+    pub(crate) fn new(club: &'a Club, competitor: Competitor<'a>, entry: &'a Entry) -> Self {
+        Self {
+            lane: entry.lane(),
+            competitor,
+            club,
+            entry_time: entry.entry_time(),
+        }
+    }
+}
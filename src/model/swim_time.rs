@@ -0,0 +1,268 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    iter::Sum,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use chrono::Duration;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+/// A swim time with the resolution of a hundredth of a second, written `HH:MM:SS.hh` in
+/// documents.
+///
+/// Times order from fastest to slowest, a missing time (`NT`) being slower than any time.
+/// Documents hold at most two digits of hours, so times beyond [`SwimTime::MAX`] cannot be
+/// serialized, and are displayed as that time.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum SwimTime {
+    /// A time in hundredths of a second, at most [`SwimTime::MAX`]. [`SwimTime::from_hundredths`]
+    /// and [`SwimTime::from_hms`] check it.
+    Time(u32),
+
+    /// No time, written `NT`.
+    NoTime,
+}
+
+const MAX_HUNDREDTHS: u32 = 35_999_999;
+
+#[derive(Error, PartialEq, Debug, Clone)]
+#[error("Invalid swim time {0}, expected HH:MM:SS.hh or NT.")]
+pub struct ParseSwimTimeError(pub String);
+
+impl SwimTime {
+    /// The slowest time a document can hold, `99:59:59.99`.
+    pub const MAX: SwimTime = SwimTime::Time(MAX_HUNDREDTHS);
+
+    /// The time of `hundredths`, `None` beyond [`SwimTime::MAX`].
+    pub fn from_hundredths(hundredths: u32) -> Option<Self> {
+        Some(Self::Time(hundredths)).filter(|time| *time <= Self::MAX)
+    }
+
+    /// The time of `hours`, `minutes`, `seconds` and `hundredths`, `None` beyond
+    /// [`SwimTime::MAX`].
+    pub fn from_hms(hours: u32, minutes: u32, seconds: u32, hundredths: u32) -> Option<Self> {
+        hours
+            .checked_mul(60)?
+            .checked_add(minutes)?
+            .checked_mul(60)?
+            .checked_add(seconds)?
+            .checked_mul(100)?
+            .checked_add(hundredths)
+            .filter(|hundredths| *hundredths <= MAX_HUNDREDTHS)
+            .map(Self::Time)
+    }
+
+    /// The time in hundredths of a second, `None` for no time.
+    pub fn hundredths(&self) -> Option<u32> {
+        match self {
+            SwimTime::Time(hundredths) => Some(*hundredths),
+            SwimTime::NoTime => None,
+        }
+    }
+
+    pub fn is_no_time(&self) -> bool {
+        *self == SwimTime::NoTime
+    }
+
+    pub fn to_duration(&self) -> Option<Duration> {
+        self.hundredths()
+            .map(|hundredths| Duration::milliseconds(i64::from(hundredths) * 10))
+    }
+}
+
+impl Display for SwimTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SwimTime::Time(hundredths) => {
+                let hundredths = (*hundredths).min(MAX_HUNDREDTHS);
+                let seconds = hundredths / 100;
+
+                write!(
+                    f,
+                    "{:02}:{:02}:{:02}.{:02}",
+                    seconds / 3600,
+                    seconds / 60 % 60,
+                    seconds % 60,
+                    hundredths % 100
+                )
+            }
+            SwimTime::NoTime => f.write_str("NT"),
+        }
+    }
+}
+
+impl FromStr for SwimTime {
+    type Err = ParseSwimTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "NT" {
+            return Ok(SwimTime::NoTime);
+        }
+
+        let error = || ParseSwimTimeError(s.to_string());
+
+        let (time, fraction) = s.split_once('.').unwrap_or((s, "0"));
+        let mut parts = time.split(':');
+        let (Some(hours), Some(minutes), Some(seconds), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(error());
+        };
+
+        let number = |part: &str, max: u32| {
+            part.parse::<u32>()
+                .ok()
+                .filter(|value| *value <= max && !part.starts_with('+'))
+                .ok_or_else(error)
+        };
+        let hours = number(hours, 99)?;
+        let minutes = number(minutes, 59)?;
+        let seconds = number(seconds, 59)?;
+
+        // A single digit counts tenths, further digits are truncated to hundredths.
+        if fraction.is_empty() || !fraction.chars().all(|c| c.is_ascii_digit()) {
+            return Err(error());
+        }
+        let hundredths = format!("{:0<2}", &fraction[..fraction.len().min(2)]);
+        let hundredths = number(&hundredths, 99)?;
+
+        SwimTime::from_hms(hours, minutes, seconds, hundredths).ok_or_else(error)
+    }
+}
+
+impl Add for SwimTime {
+    type Output = SwimTime;
+
+    /// The sum saturates at [`SwimTime::MAX`], adding no time gives no time.
+    fn add(self, rhs: SwimTime) -> SwimTime {
+        match (self, rhs) {
+            (SwimTime::Time(a), SwimTime::Time(b)) => {
+                SwimTime::Time(a.saturating_add(b).min(MAX_HUNDREDTHS))
+            }
+            _ => SwimTime::NoTime,
+        }
+    }
+}
+
+impl Sub for SwimTime {
+    type Output = SwimTime;
+
+    /// The difference saturates at zero, subtracting no time gives no time.
+    fn sub(self, rhs: SwimTime) -> SwimTime {
+        match (self, rhs) {
+            (SwimTime::Time(a), SwimTime::Time(b)) => SwimTime::Time(a.saturating_sub(b)),
+            _ => SwimTime::NoTime,
+        }
+    }
+}
+
+impl Sum for SwimTime {
+    fn sum<I: Iterator<Item = SwimTime>>(iter: I) -> SwimTime {
+        iter.fold(SwimTime::Time(0), Add::add)
+    }
+}
+
+impl Serialize for SwimTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            SwimTime::Time(hundredths) if *hundredths > MAX_HUNDREDTHS => {
+                Err(serde::ser::Error::custom(format!(
+                    "swim time of {hundredths} hundredths is beyond {}",
+                    SwimTime::MAX
+                )))
+            }
+            _ => serializer.serialize_str(&self.to_string()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SwimTime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(Ok(SwimTime::Time(125)), "00:00:01.25".parse());
+        assert_eq!(Ok(SwimTime::Time(120)), "00:00:01.2".parse());
+        assert_eq!(Ok(SwimTime::Time(129)), "00:00:01.299".parse());
+        assert_eq!(Ok(SwimTime::Time(100)), "00:00:01".parse());
+        assert_eq!(
+            SwimTime::from_hms(1, 5, 0, 0),
+            "01:05:00.00".parse::<SwimTime>().ok()
+        );
+        assert_eq!(Ok(SwimTime::MAX), "99:59:59.99".parse());
+        assert_eq!(Ok(SwimTime::NoTime), "NT".parse());
+
+        assert!("".parse::<SwimTime>().is_err());
+        assert!("00:01.25".parse::<SwimTime>().is_err());
+        assert!("00:60:00.00".parse::<SwimTime>().is_err());
+        assert!("00:00:01.".parse::<SwimTime>().is_err());
+        assert!("00:00:+1.25".parse::<SwimTime>().is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("00:00:01.25", SwimTime::Time(125).to_string());
+        assert_eq!("00:01:02.34", SwimTime::Time(6234).to_string());
+        assert_eq!(
+            "01:05:00.00",
+            SwimTime::from_hms(1, 5, 0, 0).unwrap().to_string()
+        );
+        assert_eq!(
+            "10:00:00.01",
+            SwimTime::from_hms(10, 0, 0, 1).unwrap().to_string()
+        );
+        assert_eq!("NT", SwimTime::NoTime.to_string());
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(Some(SwimTime::MAX), SwimTime::from_hms(99, 59, 59, 99));
+        assert_eq!(None, SwimTime::from_hms(100, 0, 0, 0));
+        assert_eq!(None, SwimTime::from_hms(u32::MAX, 0, 0, 0));
+        assert_eq!(None, SwimTime::from_hms(0, 0, 0, u32::MAX));
+        assert_eq!(Some(SwimTime::MAX), SwimTime::from_hundredths(35_999_999));
+        assert_eq!(None, SwimTime::from_hundredths(36_000_000));
+
+        assert_eq!(SwimTime::MAX, SwimTime::MAX + SwimTime::Time(1));
+        assert_eq!("99:59:59.99", SwimTime::Time(u32::MAX).to_string());
+        assert_eq!(
+            Ok(SwimTime::MAX),
+            SwimTime::Time(u32::MAX).to_string().parse()
+        );
+        assert!(fast_xml::se::to_string(&SwimTime::Time(u32::MAX)).is_err());
+        assert_eq!("NT", SwimTime::NoTime.to_string());
+    }
+
+    #[test]
+    fn arithmetic_and_ordering() {
+        let a = SwimTime::Time(3000);
+        let b = SwimTime::Time(2950);
+
+        assert_eq!(SwimTime::Time(5950), a + b);
+        assert_eq!(SwimTime::Time(50), a - b);
+        assert_eq!(SwimTime::Time(0), b - a);
+        assert_eq!(SwimTime::NoTime, a + SwimTime::NoTime);
+        assert_eq!(SwimTime::Time(5950), [a, b].into_iter().sum());
+
+        assert!(b < a);
+        assert!(a < SwimTime::NoTime);
+        assert_eq!(Some(b), [a, SwimTime::NoTime, b].into_iter().min());
+    }
+}
@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use super::stroke::Stroke;
+use crate::serialization::preserve::Preserve;
 
-#[derive(Serialize, Deserialize, PartialEq, Default, Debug)]
+use super::{extra::Extra, stroke::Stroke};
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
 #[serde(rename = "SWIMSTYLE")]
 pub struct SwimStyle {
     #[serde(rename = "swimstyleid")]
@@ -16,6 +18,39 @@ pub struct SwimStyle {
     pub name: Option<String>,
 
     pub stroke: Stroke,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+impl SwimStyle {
+    pub fn new(id: u64, distance: u64, relay_count: u64, stroke: Stroke) -> Self {
+        Self {
+            id,
+            distance,
+            relay_count,
+            stroke,
+            ..Default::default()
+        }
+    }
+
+    /// Whether both swim styles describe the same race, regardless of their file specific id and
+    /// name.
+    pub fn is_same_race(&self, other: &SwimStyle) -> bool {
+        self.distance == other.distance
+            && self.relay_count == other.relay_count
+            && self.stroke == other.stroke
+    }
+}
+
+impl Preserve for SwimStyle {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
 }
 
 #[cfg(test)]
@@ -54,6 +89,7 @@ mod tests {
             relay_count: 1,
             name: Some("50m swim".into()),
             stroke: Stroke::Unknown,
+            extra: Extra::default(),
         };
 
         let result = se::to_string(&swim_style);
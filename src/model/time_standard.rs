@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use crate::serialization::preserve::{preserve_child, restore_child, Node, Preserve};
+
+use super::{extra::Extra, swim_time::SwimTime, swimstyle::SwimStyle};
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[serde(rename = "TIMESTANDARD")]
+pub struct TimeStandard {
+    #[serde(rename = "swimtime", default)]
+    pub swim_time: Option<SwimTime>,
+
+    #[serde(rename = "SWIMSTYLE")]
+    pub swim_style: SwimStyle,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+impl TimeStandard {
+    pub fn new(swim_style: SwimStyle, swim_time: SwimTime) -> Self {
+        Self {
+            swim_style,
+            swim_time: Some(swim_time),
+            extra: Extra::default(),
+        }
+    }
+}
+
+impl Preserve for TimeStandard {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_child(Some(&mut self.swim_style), node, "SWIMSTYLE");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_child(Some(&self.swim_style), node, "SWIMSTYLE");
+    }
+}
@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
+
+use crate::{
+    collection::Collection,
+    serialization::preserve::{
+        preserve_child, preserve_collection, restore_child, restore_collection, Node, Preserve,
+    },
+};
+
+use super::{
+    age_group::AgeGroup, course::Course, extra::Extra, gender::Gender, swim_time::SwimTime,
+    swimstyle::SwimStyle, time_standard::TimeStandard,
+};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename = "TIMESTANDARDLIST")]
+pub struct TimeStandardList {
+    #[serde(rename = "timestandardlistid")]
+    pub id: u32,
+
+    pub code: Option<String>,
+
+    pub course: Course,
+
+    pub gender: Gender,
+
+    pub name: String,
+
+    pub r#type: Option<TimeStandardListType>,
+
+    #[serde(rename = "AGEGROUP")]
+    pub age_group: Option<AgeGroup>,
+
+    #[serde(rename = "TIMESTANDARDS")]
+    pub time_standards: Collection<TimeStandard>,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+impl TimeStandardList {
+    pub fn new(id: u32, course: Course, gender: Gender, name: String) -> Self {
+        Self {
+            id,
+            code: None,
+            course,
+            gender,
+            name,
+            r#type: None,
+            age_group: None,
+            time_standards: Vec::new().into(),
+            extra: Extra::default(),
+        }
+    }
+
+    pub fn time_standard_for(&self, swim_style: &SwimStyle) -> Option<&TimeStandard> {
+        self.time_standards
+            .iter()
+            .find(|time_standard| time_standard.swim_style.is_same_race(swim_style))
+    }
+
+    /// Whether this list applies to a swimmer of `age`.
+    ///
+    /// Lists without an age group apply to everyone, while lists with one only apply to a known
+    /// age it includes.
+    pub fn covers_age(&self, age: Option<u16>) -> bool {
+        self.age_group
+            .as_ref()
+            .is_none_or(|age_group| age.is_some_and(|age| age_group.includes_age(age)))
+    }
+
+    /// Whether `time` meets `standard` according to the type of this list.
+    ///
+    /// A missing time, or no time, never meets a maximum and always meets a minimum. Default
+    /// lists only provide entry times and are always met.
+    pub fn is_met(&self, time: Option<SwimTime>, standard: SwimTime) -> bool {
+        match self.r#type.clone().unwrap_or_default() {
+            TimeStandardListType::Maximum => time.is_some_and(|time| time <= standard),
+            TimeStandardListType::Minimum => time.is_none_or(|time| time >= standard),
+            TimeStandardListType::Default => true,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, IntoStaticStr, PartialEq, Default, Debug, Clone)]
+#[serde(rename_all = "UPPERCASE", into = "&str")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum TimeStandardListType {
+    #[default]
+    Maximum,
+    Minimum,
+    Default,
+}
+
+impl Preserve for TimeStandardList {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_child(self.age_group.as_mut(), node, "AGEGROUP");
+        preserve_collection(&mut self.time_standards, node, "TIMESTANDARDS");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_child(self.age_group.as_ref(), node, "AGEGROUP");
+        restore_collection(&self.time_standards, node, "TIMESTANDARDS");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fast_xml::{de, se};
+
+    use crate::model::Stroke;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_empty() {
+        let result = de::from_str::<TimeStandardList>(r#"<TIMESTANDARDLIST/>"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deserialize_basic() {
+        let result = de::from_str::<TimeStandardList>(
+            r#"<TIMESTANDARDLIST timestandardlistid="7" code="QT" course="SCM" gender="F" name="Qualifying times" type="MAXIMUM"><AGEGROUP agegroupid="1" agemin="12" agemax="13"/><TIMESTANDARDS><TIMESTANDARD swimtime="00:00:31.50"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/></TIMESTANDARD></TIMESTANDARDS></TIMESTANDARDLIST>"#,
+        );
+        assert!(result.is_ok());
+
+        let list = result.unwrap();
+        assert_eq!(7, list.id);
+        assert_eq!(Some(TimeStandardListType::Maximum), list.r#type);
+        assert!(list.age_group.is_some());
+        assert_eq!(1, list.time_standards.len());
+
+        let free = SwimStyle {
+            distance: 50,
+            relay_count: 1,
+            stroke: Stroke::Free,
+            ..Default::default()
+        };
+        let time_standard = list.time_standard_for(&free);
+        assert!(time_standard.is_some());
+        assert_eq!(Some(SwimTime::Time(3150)), time_standard.unwrap().swim_time);
+    }
+
+    #[test]
+    fn is_met() {
+        let mut list = TimeStandardList::new(1, Course::LCM, Gender::All, "Standards".into());
+        let standard = SwimTime::Time(3000);
+
+        assert!(list.is_met(Some(SwimTime::Time(3000)), standard));
+        assert!(!list.is_met(Some(SwimTime::Time(3100)), standard));
+        assert!(!list.is_met(None, standard));
+
+        list.r#type = Some(TimeStandardListType::Minimum);
+        assert!(!list.is_met(Some(SwimTime::Time(2900)), standard));
+        assert!(list.is_met(Some(SwimTime::Time(3100)), standard));
+        assert!(list.is_met(None, standard));
+
+        list.r#type = Some(TimeStandardListType::Default);
+        assert!(list.is_met(None, standard));
+    }
+
+    #[test]
+    fn serialize_type() {
+        let result = se::to_string(&TimeStandardListType::Minimum);
+        assert!(result.is_ok());
+        assert_eq!("MINIMUM", result.unwrap());
+    }
+}
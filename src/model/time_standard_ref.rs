@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+
+use crate::serialization::preserve::{preserve_child, restore_child, Node, Preserve};
+
+use super::{
+    extra::Extra, fee::Fee, time_standard::TimeStandard, time_standard_list::TimeStandardList,
+};
+
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[serde(rename = "TIMESTANDARDREF")]
+pub struct TimeStandardRef {
+    #[serde(rename = "timestandardlistid")]
+    pub time_standard_list_id: u32,
+
+    pub marker: Option<String>,
+
+    #[serde(rename = "FEE")]
+    pub fee: Option<Fee>,
+
+    #[serde(skip)]
+    pub extra: Extra,
+}
+
+/// Outcome of checking an entry against one time standard referenced by its event, as returned
+/// by [`Lenex::check_entry`](super::Lenex::check_entry).
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct TimeStandardCheck<'a> {
+    pub reference: &'a TimeStandardRef,
+    pub list: &'a TimeStandardList,
+    pub time_standard: &'a TimeStandard,
+    pub status: TimeStandardStatus<'a>,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum TimeStandardStatus<'a> {
+    /// The entry time meets the standard.
+    Met,
+
+    /// The entry time meets the standard and its results are marked with the marker.
+    Marked(&'a str),
+
+    /// The entry time does not meet a standard only used to mark results, the entry is valid.
+    Unmarked,
+
+    /// The entry time does not meet the standard, the entry is valid once the fee is paid.
+    Penalty(&'a Fee),
+
+    /// The entry time does not meet the standard, the entry is not valid.
+    NotMet,
+}
+
+impl TimeStandardStatus<'_> {
+    /// Whether the entry can be accepted, possibly against a penalty fee.
+    pub fn is_accepted(&self) -> bool {
+        !matches!(self, Self::NotMet)
+    }
+}
+
+impl TimeStandardRef {
+    pub fn new(time_standard_list_id: u32) -> Self {
+        Self {
+            time_standard_list_id,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn status(&self, met: bool) -> TimeStandardStatus<'_> {
+        match (met, &self.marker, &self.fee) {
+            (true, Some(marker), _) => TimeStandardStatus::Marked(marker),
+            (true, None, _) => TimeStandardStatus::Met,
+            (false, _, Some(fee)) => TimeStandardStatus::Penalty(fee),
+            (false, Some(_), None) => TimeStandardStatus::Unmarked,
+            (false, None, None) => TimeStandardStatus::NotMet,
+        }
+    }
+}
+
+impl Preserve for TimeStandardRef {
+    fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        &mut self.extra
+    }
+
+    fn preserve_children(&mut self, node: &Node) {
+        preserve_child(self.fee.as_mut(), node, "FEE");
+    }
+
+    fn restore_children(&self, node: &mut Node) {
+        restore_child(self.fee.as_ref(), node, "FEE");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fast_xml::de;
+
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let result = de::from_str::<TimeStandardRef>(
+            r#"<TIMESTANDARDREF timestandardlistid="3" marker="*"><FEE value="500"/></TIMESTANDARDREF>"#,
+        );
+        assert!(result.is_ok());
+
+        let reference = result.unwrap();
+        assert_eq!(3, reference.time_standard_list_id);
+        assert_eq!(Some("*".into()), reference.marker);
+        assert!(reference.fee.is_some());
+    }
+
+    #[test]
+    fn status() {
+        let mut reference = TimeStandardRef::default();
+        assert_eq!(TimeStandardStatus::Met, reference.status(true));
+        assert_eq!(TimeStandardStatus::NotMet, reference.status(false));
+        assert!(!reference.status(false).is_accepted());
+
+        reference.marker = Some("Q".into());
+        assert_eq!(TimeStandardStatus::Marked("Q"), reference.status(true));
+        assert_eq!(TimeStandardStatus::Unmarked, reference.status(false));
+
+        reference.fee = Some(Fee::default());
+        assert!(matches!(
+            reference.status(false),
+            TimeStandardStatus::Penalty(_)
+        ));
+        assert!(reference.status(false).is_accepted());
+    }
+}
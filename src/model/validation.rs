@@ -0,0 +1,394 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display, Formatter},
+};
+
+use super::{
+    club::Club, entry::Entry, event::Event, lenex::Lenex, meet::Meet, relay_position::RelayPosition,
+};
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// A problem found by [`Lenex::validate`], located by the path of the faulty element such as
+/// `MEETS/MEET[0]/CLUBS/CLUB[3]/ATHLETES/ATHLETE[2]/ENTRIES/ENTRY[0]`.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub path: String,
+    pub message: String,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => f.write_str("warning"),
+            Severity::Error => f.write_str("error"),
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}: {}", self.severity, self.path, self.message)
+    }
+}
+
+impl Lenex {
+    /// Checks that the identifiers and references of the document are consistent.
+    ///
+    /// Broken references and colliding identifiers are errors, while entries that are unlikely
+    /// to be accepted by a meet manager are warnings.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validator = Validator::default();
+
+        for (index, meet) in self.meets.iter().enumerate() {
+            validator.meet(self, meet, &format!("MEETS/MEET[{index}]"));
+        }
+
+        validator.diagnostics
+    }
+}
+
+#[derive(Default)]
+struct Validator {
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Validator {
+    fn error(&mut self, path: String, message: String) {
+        self.diagnostics.push(Diagnostic {
+            severity: Severity::Error,
+            path,
+            message,
+        });
+    }
+
+    fn warning(&mut self, path: String, message: String) {
+        self.diagnostics.push(Diagnostic {
+            severity: Severity::Warning,
+            path,
+            message,
+        });
+    }
+
+    /// Reports the problems of the meet in document order. Events are looked up beforehand, as
+    /// entries and previous events may refer to events further down the document.
+    fn meet(&mut self, lenex: &Lenex, meet: &Meet, path: &str) {
+        let mut events: HashMap<u32, &Event> = HashMap::new();
+        for event in meet.sessions().iter().flat_map(|session| session.events()) {
+            events.entry(event.id).or_insert(event);
+        }
+
+        let mut session_numbers = HashSet::new();
+        let mut event_paths: HashMap<u32, String> = HashMap::new();
+        let mut age_group_ids = HashSet::new();
+        let mut heat_ids = HashSet::new();
+
+        for (session_index, session) in meet.sessions().iter().enumerate() {
+            let session_path = format!("{path}/SESSIONS/SESSION[{session_index}]");
+            if !session_numbers.insert(session.number()) {
+                self.error(
+                    session_path.clone(),
+                    format!("Session number {} is already used.", session.number()),
+                );
+            }
+
+            for (event_index, event) in session.events().iter().enumerate() {
+                let event_path = format!("{session_path}/EVENTS/EVENT[{event_index}]");
+                match event_paths.get(&event.id) {
+                    Some(other) => self.error(
+                        event_path.clone(),
+                        format!("Event id {} is already used by {other}.", event.id),
+                    ),
+                    None => {
+                        event_paths.insert(event.id, event_path.clone());
+                    }
+                }
+
+                if let Some(prev_event_id) = event.prev_event_id {
+                    if !events.contains_key(&prev_event_id) {
+                        self.error(
+                            event_path.clone(),
+                            format!("Previous event {prev_event_id} does not exist."),
+                        );
+                    }
+                }
+
+                self.event(lenex, event, &event_path, &mut age_group_ids, &mut heat_ids);
+            }
+        }
+
+        let mut club_ids = HashSet::new();
+        let mut athlete_ids = HashSet::new();
+        for (club_index, club) in meet.clubs().iter().enumerate() {
+            let club_path = format!("{path}/CLUBS/CLUB[{club_index}]");
+            if !club_ids.insert(club.id()) {
+                self.error(
+                    club_path.clone(),
+                    format!("Club id {} is already used.", club.id()),
+                );
+            }
+
+            self.club(club, &club_path, &events, &mut athlete_ids);
+        }
+    }
+
+    /// Checks the event at `path`, whose age group and heat ids must be unique within the meet.
+    fn event(
+        &mut self,
+        lenex: &Lenex,
+        event: &Event,
+        path: &str,
+        age_group_ids: &mut HashSet<u32>,
+        heat_ids: &mut HashSet<u32>,
+    ) {
+        for (index, age_group) in event.age_groups().iter().enumerate() {
+            if !age_group_ids.insert(age_group.id) {
+                self.error(
+                    format!("{path}/AGEGROUPS/AGEGROUP[{index}]"),
+                    format!("Age group id {} is already used.", age_group.id),
+                );
+            }
+        }
+
+        for (index, heat) in event.heats().iter().enumerate() {
+            if !heat_ids.insert(heat.id) {
+                self.error(
+                    format!("{path}/HEATS/HEAT[{index}]"),
+                    format!("Heat id {} is already used.", heat.id),
+                );
+            }
+        }
+
+        for (index, reference) in event.time_standard_refs().iter().enumerate() {
+            if lenex
+                .time_standard_list(reference.time_standard_list_id)
+                .is_none()
+            {
+                self.error(
+                    format!("{path}/TIMESTANDARDREFS/TIMESTANDARDREF[{index}]"),
+                    format!(
+                        "Time standard list {} does not exist.",
+                        reference.time_standard_list_id
+                    ),
+                );
+            }
+        }
+    }
+
+    fn club(
+        &mut self,
+        club: &Club,
+        path: &str,
+        events: &HashMap<u32, &Event>,
+        athlete_ids: &mut HashSet<u32>,
+    ) {
+        for (athlete_index, athlete) in club.athletes().iter().enumerate() {
+            let athlete_path = format!("{path}/ATHLETES/ATHLETE[{athlete_index}]");
+            if !athlete_ids.insert(athlete.id()) {
+                self.error(
+                    athlete_path.clone(),
+                    format!("Athlete id {} is already used.", athlete.id()),
+                );
+            }
+
+            for (index, entry) in athlete.entries().iter().enumerate() {
+                let entry_path = format!("{athlete_path}/ENTRIES/ENTRY[{index}]");
+                if let Some(event) = self.entry(entry, &entry_path, events) {
+                    if !event.accepts_gender(athlete.gender()) {
+                        self.warning(
+                            entry_path,
+                            format!(
+                                "Event {} is not open to the gender of the athlete.",
+                                event.id
+                            ),
+                        );
+                    }
+                }
+            }
+
+            for (index, result) in athlete.results().iter().enumerate() {
+                self.event_reference(
+                    result.event_id(),
+                    format!("{athlete_path}/RESULTS/RESULT[{index}]"),
+                    events,
+                );
+            }
+        }
+
+        for (relay_index, relay) in club.relays().iter().enumerate() {
+            let relay_path = format!("{path}/RELAYS/RELAY[{relay_index}]");
+
+            for (index, entry) in relay.entries().iter().enumerate() {
+                let entry_path = format!("{relay_path}/ENTRIES/ENTRY[{index}]");
+                self.entry(entry, &entry_path, events);
+                self.relay_positions(club, entry.relay_positions(), &entry_path);
+            }
+
+            for (index, result) in relay.results().iter().enumerate() {
+                let result_path = format!("{relay_path}/RESULTS/RESULT[{index}]");
+                self.event_reference(result.event_id(), result_path.clone(), events);
+                self.relay_positions(club, result.relay_positions(), &result_path);
+            }
+        }
+    }
+
+    fn entry<'a>(
+        &mut self,
+        entry: &Entry,
+        path: &str,
+        events: &HashMap<u32, &'a Event>,
+    ) -> Option<&'a Event> {
+        let event = self.event_reference(entry.event_id(), path.to_string(), events)?;
+
+        if let Some(heat_id) = entry.heat_id() {
+            if !event.heats().iter().any(|heat| heat.id == heat_id) {
+                self.warning(
+                    path.to_string(),
+                    format!("Heat {heat_id} is not a heat of event {}.", event.id),
+                );
+            }
+        }
+
+        Some(event)
+    }
+
+    fn event_reference<'a>(
+        &mut self,
+        event_id: u32,
+        path: String,
+        events: &HashMap<u32, &'a Event>,
+    ) -> Option<&'a Event> {
+        let event = events.get(&event_id).copied();
+        if event.is_none() {
+            self.error(path, format!("Event {event_id} does not exist."));
+        }
+
+        event
+    }
+
+    fn relay_positions(&mut self, club: &Club, positions: &[RelayPosition], path: &str) {
+        for (index, position) in positions.iter().enumerate() {
+            if let Some(athlete_id) = position.athlete_id {
+                if club.athlete(athlete_id).is_none() {
+                    self.error(
+                        format!("{path}/RELAYPOSITIONS/RELAYPOSITION[{index}]"),
+                        format!("Athlete {athlete_id} is not an athlete of the club."),
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lenex(meet: &str) -> Lenex {
+        Lenex::try_from(format!(
+            r#"<LENEX version="3.0"><CONSTRUCTOR name="lenex-rs" registration="lenex-rs" version="0.1.0"><CONTACT name="lenex-rs" country="CA" email="lenex@example.com" internet="https://github.com/francisbr/lenex-rs"/></CONSTRUCTOR><MEETS>{meet}</MEETS></LENEX>"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn validate_valid_document() {
+        let lenex = lenex(
+            r#"<MEET name="Meet" city="Montreal" nation="CAN"><SESSIONS><SESSION date="2023-02-11" number="1"><EVENTS><EVENT eventid="1" number="1"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/><AGEGROUPS><AGEGROUP agegroupid="1"/><AGEGROUP agegroupid="2"/></AGEGROUPS></EVENT><EVENT eventid="2" number="2" preveventid="1"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/></EVENT></EVENTS></SESSION></SESSIONS><CLUBS><CLUB clubid="1" name="Club"><ATHLETES><ATHLETE athleteid="1" firstname="Jane" lastname="Doe" gender="F" birthdate="2010-01-01"><ENTRIES><ENTRY eventid="1"/></ENTRIES></ATHLETE></ATHLETES></CLUB></CLUBS></MEET>"#,
+        );
+
+        assert!(lenex.validate().is_empty());
+    }
+
+    #[test]
+    fn validate_broken_references() {
+        let lenex = lenex(
+            r#"<MEET name="Meet" city="Montreal" nation="CAN"><SESSIONS><SESSION date="2023-02-11" number="1"><EVENTS><EVENT eventid="1" number="1" gender="M"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/><AGEGROUPS><AGEGROUP agegroupid="1"/><AGEGROUP agegroupid="1"/></AGEGROUPS></EVENT><EVENT eventid="2" number="2" preveventid="9"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/><TIMESTANDARDREFS><TIMESTANDARDREF timestandardlistid="4"/></TIMESTANDARDREFS></EVENT></EVENTS></SESSION><SESSION date="2023-02-12" number="1"><EVENTS/></SESSION></SESSIONS><CLUBS><CLUB clubid="1" name="Club"><ATHLETES><ATHLETE athleteid="1" firstname="Jane" lastname="Doe" gender="F" birthdate="2010-01-01"><ENTRIES><ENTRY eventid="1"/><ENTRY eventid="3"/></ENTRIES></ATHLETE></ATHLETES><RELAYS><RELAY number="1" gender="F"><ENTRIES><ENTRY eventid="2"><RELAYPOSITIONS><RELAYPOSITION athleteid="5" number="1"/></RELAYPOSITIONS></ENTRY></ENTRIES></RELAY></RELAYS></CLUB></CLUBS></MEET>"#,
+        );
+
+        let diagnostics = lenex.validate();
+        let messages: Vec<_> = diagnostics.iter().map(|d| d.to_string()).collect();
+
+        assert_eq!(
+            vec![
+                "error: MEETS/MEET[0]/SESSIONS/SESSION[0]/EVENTS/EVENT[0]/AGEGROUPS/AGEGROUP[1]: Age group id 1 is already used.",
+                "error: MEETS/MEET[0]/SESSIONS/SESSION[0]/EVENTS/EVENT[1]: Previous event 9 does not exist.",
+                "error: MEETS/MEET[0]/SESSIONS/SESSION[0]/EVENTS/EVENT[1]/TIMESTANDARDREFS/TIMESTANDARDREF[0]: Time standard list 4 does not exist.",
+                "error: MEETS/MEET[0]/SESSIONS/SESSION[1]: Session number 1 is already used.",
+                "warning: MEETS/MEET[0]/CLUBS/CLUB[0]/ATHLETES/ATHLETE[0]/ENTRIES/ENTRY[0]: Event 1 is not open to the gender of the athlete.",
+                "error: MEETS/MEET[0]/CLUBS/CLUB[0]/ATHLETES/ATHLETE[0]/ENTRIES/ENTRY[1]: Event 3 does not exist.",
+                "error: MEETS/MEET[0]/CLUBS/CLUB[0]/RELAYS/RELAY[0]/ENTRIES/ENTRY[0]/RELAYPOSITIONS/RELAYPOSITION[0]: Athlete 5 is not an athlete of the club.",
+            ],
+            messages
+        );
+        assert_eq!(Severity::Warning, diagnostics[4].severity);
+    }
+
+    #[test]
+    fn validate_duplicate_event_ids() {
+        let lenex = lenex(
+            r#"<MEET name="Meet" city="Montreal" nation="CAN"><SESSIONS><SESSION date="2023-02-11" number="1"><EVENTS><EVENT eventid="1" number="1"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/></EVENT></EVENTS></SESSION><SESSION date="2023-02-12" number="2"><EVENTS><EVENT eventid="1" number="2"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/></EVENT></EVENTS></SESSION></SESSIONS><CLUBS/></MEET>"#,
+        );
+
+        let diagnostics = lenex.validate();
+        assert_eq!(1, diagnostics.len());
+        assert_eq!(
+            "MEETS/MEET[0]/SESSIONS/SESSION[1]/EVENTS/EVENT[0]",
+            diagnostics[0].path
+        );
+        assert_eq!(
+            "Event id 1 is already used by MEETS/MEET[0]/SESSIONS/SESSION[0]/EVENTS/EVENT[0].",
+            diagnostics[0].message
+        );
+    }
+
+    #[test]
+    fn validate_duplicate_club_and_age_group_ids() {
+        let clubs: String = (1..=12)
+            .map(|id| {
+                let id = if id == 12 { 3 } else { id };
+                let event_id = if id == 11 { 9 } else { 1 };
+
+                format!(
+                    r#"<CLUB clubid="{id}" name="Club"><ATHLETES><ATHLETE athleteid="{id}" firstname="Jane" lastname="Doe" gender="F"><ENTRIES><ENTRY eventid="{event_id}"/></ENTRIES></ATHLETE></ATHLETES></CLUB>"#
+                )
+            })
+            .collect();
+        let lenex = lenex(&format!(
+            r#"<MEET name="Meet" city="Montreal" nation="CAN"><SESSIONS><SESSION date="2023-02-11" number="1"><EVENTS><EVENT eventid="1" number="1"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/><AGEGROUPS><AGEGROUP agegroupid="1"/></AGEGROUPS></EVENT><EVENT eventid="2" number="2"><SWIMSTYLE swimstyleid="1" distance="50" relaycount="1" stroke="FREE"/><AGEGROUPS><AGEGROUP agegroupid="1"/></AGEGROUPS></EVENT></EVENTS></SESSION></SESSIONS><CLUBS>{clubs}</CLUBS></MEET>"#
+        ));
+
+        let paths: Vec<_> = lenex
+            .validate()
+            .into_iter()
+            .map(|diagnostic| (diagnostic.path, diagnostic.message))
+            .collect();
+        assert_eq!(
+            vec![
+                (
+                    "MEETS/MEET[0]/SESSIONS/SESSION[0]/EVENTS/EVENT[1]/AGEGROUPS/AGEGROUP[0]"
+                        .into(),
+                    "Age group id 1 is already used.".into()
+                ),
+                (
+                    "MEETS/MEET[0]/CLUBS/CLUB[10]/ATHLETES/ATHLETE[0]/ENTRIES/ENTRY[0]".into(),
+                    "Event 9 does not exist.".into()
+                ),
+                (
+                    "MEETS/MEET[0]/CLUBS/CLUB[11]".into(),
+                    "Club id 3 is already used.".into()
+                ),
+                (
+                    "MEETS/MEET[0]/CLUBS/CLUB[11]/ATHLETES/ATHLETE[0]".into(),
+                    "Athlete id 3 is already used.".into()
+                ),
+            ],
+            paths
+        );
+    }
+}
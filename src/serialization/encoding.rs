@@ -0,0 +1,157 @@
+use encoding_rs::{Encoding, UTF_8, WINDOWS_1252};
+
+use crate::{error::Error, Result};
+
+/// Decodes a document to UTF-8.
+///
+/// The encoding is taken from the byte order mark, then from the `encoding` attribute of the
+/// XML declaration. Documents declaring neither are read as UTF-8, falling back to
+/// Windows-1252 (a superset of ISO-8859-1) for legacy exports that are not valid UTF-8.
+pub fn decode(bytes: &[u8]) -> Result<String> {
+    if let Some((encoding, bom_length)) = Encoding::for_bom(bytes) {
+        return decode_with(encoding, &bytes[bom_length..]);
+    }
+
+    match declared_encoding(bytes) {
+        Some(label) => {
+            let encoding =
+                Encoding::for_label(label.as_bytes()).ok_or(Error::UnsupportedEncoding(label))?;
+
+            decode_with(encoding, bytes)
+        }
+        None => decode_with(UTF_8, bytes).or_else(|_| decode_with(WINDOWS_1252, bytes)),
+    }
+}
+
+/// Decodes an element of a document read in `encoding`, as declared by the document.
+///
+/// Without a declared encoding, the element is read as UTF-8, falling back to Windows-1252 like
+/// [`decode`] does.
+pub fn decode_fragment(bytes: &[u8], encoding: Option<&'static Encoding>) -> Result<String> {
+    match encoding {
+        Some(encoding) => decode_with(encoding, bytes),
+        None => decode_with(UTF_8, bytes).or_else(|_| decode_with(WINDOWS_1252, bytes)),
+    }
+}
+
+/// The label to declare for `text` written in the encoding named by `label`.
+///
+/// Encodings that cannot be written are declared as the encoding written instead. ISO-8859-1 is
+/// written as its superset Windows-1252, so text holding characters that Windows-1252 writes in
+/// 0x80 to 0x9F, such as `€` or curly quotes, is declared Windows-1252.
+pub fn output_label<'a>(label: &'a str, encoding: &'static Encoding, text: &str) -> &'a str {
+    let output = encoding.output_encoding();
+    if output != encoding {
+        return output.name();
+    }
+
+    if output == WINDOWS_1252 && text.chars().any(is_windows_1252_only) {
+        return WINDOWS_1252.name();
+    }
+
+    label
+}
+
+/// Whether `c` is one of the characters Windows-1252 writes in place of the control characters
+/// of ISO-8859-1.
+fn is_windows_1252_only(c: char) -> bool {
+    let mut buffer = [0; 4];
+    let (_, _, unmappable) = WINDOWS_1252.encode(c.encode_utf8(&mut buffer));
+
+    c > '\u{ff}' && !unmappable
+}
+
+fn decode_with(encoding: &'static Encoding, bytes: &[u8]) -> Result<String> {
+    encoding
+        .decode_without_bom_handling_and_without_replacement(bytes)
+        .map(String::from)
+        .ok_or(Error::Encoding(encoding.name()))
+}
+
+/// Returns the `encoding` attribute of the XML declaration starting `bytes`, if any.
+///
+/// The declaration only holds ASCII characters in every encoding a Lenex document can use, so it
+/// is read before knowing the encoding.
+fn declared_encoding(bytes: &[u8]) -> Option<String> {
+    let declaration = bytes.strip_prefix(b"<?xml")?;
+    let end = declaration.windows(2).position(|w| w == b"?>")?;
+    let declaration = String::from_utf8_lossy(&declaration[..end]);
+
+    let (_, value) = declaration.split_once("encoding")?;
+    let value = value.trim_start().strip_prefix('=')?.trim_start();
+    let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let (label, _) = value[1..].split_once(quote)?;
+
+    Some(label.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_utf8() {
+        let result =
+            decode("<?xml version=\"1.0\" encoding=\"UTF-8\"?><LENEX name=\"Québec\"/>".as_bytes());
+        assert!(result.is_ok());
+        assert!(result.unwrap().contains("Québec"));
+    }
+
+    #[test]
+    fn decode_declared_latin1() {
+        let mut bytes = b"<?xml version='1.0' encoding='ISO-8859-1'?><LENEX name=\"Qu".to_vec();
+        bytes.extend([0xe9]);
+        bytes.extend(b"bec M\xfcnchen\"/>");
+
+        let result = decode(&bytes);
+        assert!(result.is_ok());
+        assert!(result.unwrap().contains("Québec München"));
+    }
+
+    #[test]
+    fn decode_undeclared_windows_1252() {
+        let result = decode(b"<LENEX name=\"Fran\xe7ois \x80\"/>");
+        assert!(result.is_ok());
+        assert!(result.unwrap().contains("François €"));
+    }
+
+    #[test]
+    fn output_labels() {
+        let latin1 = Encoding::for_label(b"ISO-8859-1").unwrap();
+        assert_eq!("ISO-8859-1", output_label("ISO-8859-1", latin1, "Québec"));
+        assert_eq!("ISO-8859-1", output_label("ISO-8859-1", latin1, "東京"));
+        assert_eq!("windows-1252", output_label("ISO-8859-1", latin1, "10 €"));
+        assert_eq!("windows-1252", output_label("ISO-8859-1", latin1, "“A”"));
+
+        let utf16 = Encoding::for_label(b"UTF-16").unwrap();
+        assert_eq!("UTF-8", output_label("UTF-16", utf16, "10 €"));
+    }
+
+    #[test]
+    fn decode_bom() {
+        let mut bytes = vec![0xff, 0xfe];
+        for unit in "<LENEX name=\"Zoë\"/>".encode_utf16() {
+            bytes.extend(unit.to_le_bytes());
+        }
+
+        let result = decode(&bytes);
+        assert!(result.is_ok());
+        assert_eq!("<LENEX name=\"Zoë\"/>", result.unwrap());
+
+        let result = decode("\u{feff}<LENEX/>".as_bytes());
+        assert!(result.is_ok());
+        assert_eq!("<LENEX/>", result.unwrap());
+    }
+
+    #[test]
+    fn decode_invalid_declared_utf8() {
+        let result = decode(b"<?xml version=\"1.0\" encoding=\"UTF-8\"?><LENEX name=\"\xe9\"/>");
+        assert!(matches!(result, Err(Error::Encoding("UTF-8"))));
+    }
+
+    #[test]
+    fn decode_unknown_encoding() {
+        let result = decode(b"<?xml version=\"1.0\" encoding=\"EBCDIC-42\"?><LENEX/>");
+        assert!(matches!(result, Err(Error::UnsupportedEncoding(_))));
+    }
+}
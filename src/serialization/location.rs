@@ -0,0 +1,172 @@
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    io::{self, BufRead, Read},
+};
+
+use fast_xml::{events::Event, DeError, Reader};
+
+use crate::error::XmlError;
+
+/// [`BufRead`] adapter counting the bytes consumed by the XML reader, so that a deserialization
+/// failure can be traced back to where it happened in the input.
+pub struct CountingReader<'c, R> {
+    inner: R,
+    consumed: &'c Cell<usize>,
+}
+
+impl<'c, R> CountingReader<'c, R> {
+    pub fn new(inner: R, consumed: &'c Cell<usize>) -> Self {
+        Self { inner, consumed }
+    }
+}
+
+impl<R: Read> Read for CountingReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.consumed.set(self.consumed.get() + read);
+
+        Ok(read)
+    }
+}
+
+impl<R: BufRead> BufRead for CountingReader<'_, R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt);
+        self.consumed.set(self.consumed.get() + amt);
+    }
+}
+
+impl XmlError {
+    /// Locates `error` in `input`, `consumed` being the number of bytes read before the failure.
+    ///
+    /// The failure is reported at the last tag read, which is the element whose attributes or
+    /// content could not be deserialized.
+    pub(crate) fn locate(error: DeError, input: &[u8], consumed: usize) -> Self {
+        let (offset, path) = element_path(&input[..consumed.min(input.len())]);
+        let before = String::from_utf8_lossy(&input[..offset]);
+
+        Self {
+            message: error.to_string(),
+            line: before.matches('\n').count() + 1,
+            column: before
+                .rsplit('\n')
+                .next()
+                .map_or(0, |line| line.chars().count())
+                + 1,
+            path,
+        }
+    }
+}
+
+/// Scans `input` and returns the offset of its last tag along with the path of the element it
+/// belongs to, such as `MEETS/MEET[0]/CLUBS/CLUB[3]`.
+///
+/// The root element is left out, and only children of collections (whose name ends with `S`)
+/// are indexed.
+fn element_path(input: &[u8]) -> (usize, String) {
+    let mut reader = Reader::from_bytes(input);
+    reader.trim_text(true).check_end_names(false);
+
+    let mut stack: Vec<(String, Option<usize>)> = Vec::new();
+    let mut counts: Vec<HashMap<String, usize>> = vec![HashMap::new()];
+    let mut closed = None;
+    let mut offset = 0;
+
+    loop {
+        let position = reader.buffer_position();
+        let (name, is_start) = match reader.read_event_unbuffered() {
+            Ok(Event::Start(e)) => (String::from_utf8_lossy(e.name()).to_string(), true),
+            Ok(Event::Empty(e)) => (String::from_utf8_lossy(e.name()).to_string(), false),
+            Ok(Event::End(_)) => {
+                offset = tag_start(input, position);
+                closed = stack.pop();
+                counts.pop();
+                continue;
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            Ok(_) => continue,
+        };
+
+        offset = tag_start(input, position);
+
+        let is_item = stack
+            .last()
+            .is_some_and(|(parent, _)| parent.ends_with('S'));
+        let count = counts
+            .last_mut()
+            .and_then(|counts| counts.get_mut(&name).map(|count| *count))
+            .unwrap_or_default();
+        if let Some(counts) = counts.last_mut() {
+            counts.insert(name.clone(), count + 1);
+        }
+
+        let element = (name, is_item.then_some(count));
+        if is_start {
+            stack.push(element);
+            counts.push(HashMap::new());
+            closed = None;
+        } else {
+            closed = Some(element);
+        }
+    }
+
+    let path = stack
+        .iter()
+        .chain(closed.iter())
+        .skip(1)
+        .map(|(name, index)| match index {
+            Some(index) => format!("{name}[{index}]"),
+            None => name.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+
+    (offset, path)
+}
+
+/// Skips the text the reader trimmed before the tag read from `position`.
+fn tag_start(input: &[u8], position: usize) -> usize {
+    input[position..]
+        .iter()
+        .position(|byte| *byte == b'<')
+        .map_or(position, |skipped| position + skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_of_open_element() {
+        let (offset, path) =
+            element_path(br#"<LENEX><MEETS><MEET><CLUBS><CLUB/><CLUB><ATHLETES><ATHLETE a="1">"#);
+
+        assert_eq!("MEETS/MEET[0]/CLUBS/CLUB[1]/ATHLETES/ATHLETE[0]", path);
+        assert_eq!(50, offset);
+    }
+
+    #[test]
+    fn path_of_closed_element() {
+        let (_, path) = element_path(
+            br#"<LENEX><MEETS><MEET><SESSIONS><SESSION></SESSION><SESSION></SESSION>"#,
+        );
+
+        assert_eq!("MEETS/MEET[0]/SESSIONS/SESSION[1]", path);
+    }
+
+    #[test]
+    fn locate() {
+        let input = b"<LENEX>\n  <MEETS>\n    <MEET name=\"\"/>\n  </MEETS>\n</LENEX>";
+        let error = XmlError::locate(DeError::Custom("missing field".into()), input, 39);
+
+        assert_eq!("missing field", error.message);
+        assert_eq!(3, error.line);
+        assert_eq!(5, error.column);
+        assert_eq!("MEETS/MEET[0]", error.path);
+    }
+}
@@ -1,10 +1,16 @@
+pub mod encoding;
+pub mod location;
+pub mod preserve;
+
 pub mod serde_time {
+    /// The format of the times of day of a document, such as `09:30`.
+    pub const TIME_FORMAT: &str = "%H:%M";
 
     pub mod optional {
         use chrono::NaiveTime;
         use serde::{Deserialize, Deserializer, Serializer};
 
-        const TIME_FORMAT: &str = "%H:%M";
+        use super::TIME_FORMAT;
 
         pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<NaiveTime>, D::Error>
         where
@@ -31,63 +37,46 @@ pub mod serde_time {
             }
         }
     }
+}
 
-    pub mod swim_time {
-        use chrono::{Duration, NaiveTime};
-        use serde::{Deserialize, Deserializer, Serializer};
-
-        const TIME_FORMAT: &str = "%H:%M:%S.%3f";
-
-        pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let s: Option<String> = Option::deserialize(deserializer)?;
-
-            match s {
-                Some(mut value) => {
-                    let mut parts = Vec::new();
-                    for (index, part) in value.split('.').enumerate() {
-                        let mut s = part.to_string();
-
-                        if index == 1 {
-                            s = format!("{s:0<3}");
-                        }
+pub mod serde_reaction_time {
+    use chrono::Duration;
+    use serde::{Deserialize, Deserializer, Serializer};
 
-                        parts.push(s)
-                    }
-                    value = parts.join(".");
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s: Option<String> = Option::deserialize(deserializer)?;
 
-                    let time = NaiveTime::parse_from_str(&value, TIME_FORMAT)
-                        .map_err(serde::de::Error::custom)?;
-                    let duration = time.signed_duration_since(
-                        NaiveTime::from_hms_opt(0, 0, 0).unwrap_or_default(),
-                    );
+        match s {
+            Some(value) => {
+                let hundredths = value
+                    .trim_start_matches('+')
+                    .parse::<i64>()
+                    .map_err(serde::de::Error::custom)?;
 
-                    Ok(Some(duration))
-                }
-                None => Ok(None),
+                Ok(Some(Duration::milliseconds(hundredths * 10)))
             }
+            None => Ok(None),
         }
+    }
 
-        pub fn serialize<S>(x: &Option<Duration>, s: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            let duration = match x {
-                Some(duration) => duration,
-                None => {
-                    return s.serialize_none();
+    pub fn serialize<S>(x: &Option<Duration>, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match x {
+            Some(duration) => {
+                let hundredths = duration.num_milliseconds() / 10;
+
+                if hundredths == 0 {
+                    s.serialize_str("0")
+                } else {
+                    s.serialize_str(&format!("{hundredths:+}"))
                 }
-            };
-
-            let seconds = format!("{:.2}", duration.num_milliseconds() as f32 / 1000.);
-            s.serialize_str(&format!(
-                "{:0>2}:{:0>2}:{:0>5}",
-                duration.num_hours(),
-                duration.num_minutes(),
-                seconds
-            ))
+            }
+            None => s.serialize_none(),
         }
     }
 }
@@ -95,7 +84,9 @@ pub mod serde_time {
 pub mod serde_age {
     use serde::{Deserialize, Deserializer, Serializer};
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<u8>, D::Error>
+    /// Reads an age bound, `-1` standing for no bound. Bounds of total age groups, such as the
+    /// 280 to 319 years of masters relays, go beyond the age of a single athlete.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<u16>, D::Error>
     where
         D: Deserializer<'de>,
     {
@@ -111,15 +102,17 @@ pub mod serde_age {
             return Ok(None);
         }
 
-        Ok(Some(x as u8))
+        u16::try_from(x)
+            .map(Some)
+            .map_err(|_| serde::de::Error::custom(format!("age {x} is out of range")))
     }
 
-    pub fn serialize<S>(x: &Option<u8>, s: S) -> Result<S::Ok, S::Error>
+    pub fn serialize<S>(x: &Option<u16>, s: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         match x {
-            Some(x) => s.serialize_u8(x.to_owned()),
+            Some(x) => s.serialize_u16(x.to_owned()),
             None => s.serialize_i8(-1),
         }
     }
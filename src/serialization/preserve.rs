@@ -0,0 +1,275 @@
+use std::{
+    cell::Cell,
+    fmt::{self, Display, Formatter},
+};
+
+use fast_xml::{escape::escape, events::Event, Reader};
+use serde::{
+    de::{self, Visitor},
+    forward_to_deserialize_any, Deserialize, Deserializer,
+};
+
+use crate::{
+    collection::Collection,
+    model::{Extra, Position},
+};
+
+/// An element of a document, as read by the lossless readers. Text and comments are left out
+/// since Lenex documents do not use them.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Node {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    /// Reads the root element of `xml`.
+    pub fn parse(xml: &str) -> fast_xml::Result<Node> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+
+        let mut stack: Vec<Node> = Vec::new();
+        loop {
+            match reader.read_event_unbuffered()? {
+                Event::Start(e) => stack.push(Self::start(&e)?),
+                Event::Empty(e) => {
+                    let node = Self::start(&e)?;
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(node),
+                        None => return Ok(node),
+                    }
+                }
+                Event::End(_) => {
+                    let node = stack
+                        .pop()
+                        .ok_or(fast_xml::Error::UnexpectedEof("unexpected end tag".into()))?;
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(node),
+                        None => return Ok(node),
+                    }
+                }
+                Event::Eof => {
+                    return Err(fast_xml::Error::UnexpectedEof("root element".into()));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn start(e: &fast_xml::events::BytesStart) -> fast_xml::Result<Node> {
+        let attributes = e
+            .attributes()
+            .map(|attribute| {
+                let attribute = attribute?;
+
+                Ok((
+                    String::from_utf8_lossy(attribute.key).into_owned(),
+                    String::from_utf8_lossy(&attribute.unescaped_value()?).into_owned(),
+                ))
+            })
+            .collect::<fast_xml::Result<_>>()?;
+
+        Ok(Node {
+            name: String::from_utf8_lossy(e.name()).into_owned(),
+            attributes,
+            children: Vec::new(),
+        })
+    }
+
+    pub fn child(&self, name: &str) -> Option<&Node> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    pub fn child_mut(&mut self, name: &str) -> Option<&mut Node> {
+        self.children.iter_mut().find(|child| child.name == name)
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}", self.name)?;
+        for (name, value) in self.attributes.iter() {
+            write!(
+                f,
+                " {name}=\"{}\"",
+                String::from_utf8_lossy(&escape(value.as_bytes()))
+            )?;
+        }
+
+        if self.children.is_empty() {
+            return f.write_str("/>");
+        }
+
+        f.write_str(">")?;
+        for child in self.children.iter() {
+            write!(f, "{child}")?;
+        }
+        write!(f, "</{}>", self.name)
+    }
+}
+
+/// A model element able to keep what it does not model of the element it was read from.
+///
+/// Implementations give access to their [`Extra`] and walk their child elements with the
+/// `preserve_*` and `restore_*` helpers.
+pub trait Preserve: for<'de> Deserialize<'de> {
+    fn extra(&self) -> &Extra;
+
+    fn extra_mut(&mut self) -> &mut Extra;
+
+    fn preserve_children(&mut self, _node: &Node) {}
+
+    fn restore_children(&self, _node: &mut Node) {}
+}
+
+/// Keeps the attributes and child elements of `node` unknown to `item`, recursively.
+pub fn preserve<T: Preserve>(item: &mut T, node: &Node) {
+    let known = field_names::<T>();
+
+    let extra = item.extra_mut();
+    extra.attributes = node
+        .attributes
+        .iter()
+        .enumerate()
+        .filter(|(_, (name, _))| !known.contains(&name.as_str()))
+        .map(|(index, (name, value))| {
+            let before = node.attributes[..index]
+                .iter()
+                .map(|(name, _)| name.as_str());
+            (Position::after(before), name.clone(), value.clone())
+        })
+        .collect();
+    extra.elements = node
+        .children
+        .iter()
+        .enumerate()
+        .filter(|(_, child)| !known.contains(&child.name.as_str()))
+        .map(|(index, child)| {
+            let before = node.children[..index]
+                .iter()
+                .map(|child| child.name.as_str());
+            (Position::after(before), child.to_string())
+        })
+        .collect();
+
+    item.preserve_children(node);
+}
+
+/// Writes the extras of `item` back into `node`, its serialization, recursively.
+pub fn restore<T: Preserve>(item: &T, node: &mut Node) {
+    item.restore_children(node);
+
+    let extra = item.extra();
+    for (position, name, value) in extra.attributes.iter() {
+        let index = position.index(node.attributes.iter().map(|(name, _)| name.as_str()));
+        node.attributes.insert(index, (name.clone(), value.clone()));
+    }
+    for (position, element) in extra.elements.iter() {
+        if let Ok(element) = Node::parse(element) {
+            let index = position.index(node.children.iter().map(|child| child.name.as_str()));
+            node.children.insert(index, element);
+        }
+    }
+}
+
+pub fn preserve_child<T: Preserve>(item: Option<&mut T>, node: &Node, name: &str) {
+    if let (Some(item), Some(child)) = (item, node.child(name)) {
+        preserve(item, child);
+    }
+}
+
+pub fn restore_child<T: Preserve>(item: Option<&T>, node: &mut Node, name: &str) {
+    if let (Some(item), Some(child)) = (item, node.child_mut(name)) {
+        restore(item, child);
+    }
+}
+
+pub fn preserve_collection<T: Preserve>(items: &mut Collection<T>, node: &Node, name: &str) {
+    if let Some(collection) = node.child(name) {
+        for (item, child) in items.iter_mut().zip(collection.children.iter()) {
+            preserve(item, child);
+        }
+    }
+}
+
+pub fn restore_collection<T: Preserve>(items: &Collection<T>, node: &mut Node, name: &str) {
+    if let Some(collection) = node.child_mut(name) {
+        for (item, child) in items.iter().zip(collection.children.iter_mut()) {
+            restore(item, child);
+        }
+    }
+}
+
+/// The attribute and child element names `T` deserializes, as declared by its derived
+/// `Deserialize` implementation.
+fn field_names<T: for<'de> Deserialize<'de>>() -> &'static [&'static str] {
+    let fields = Cell::new(&[][..]);
+    let _ = T::deserialize(FieldNames(&fields));
+
+    fields.get()
+}
+
+struct FieldNames<'f>(&'f Cell<&'static [&'static str]>);
+
+impl<'de> Deserializer<'de> for FieldNames<'_> {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Err(de::Error::custom("only structs have field names"))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.set(fields);
+
+        Err(de::Error::custom("field names read"))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Fee;
+
+    #[test]
+    fn parse_and_write() {
+        let xml = r#"<A a="1 &amp; 2"><B/><C c="3"><D/></C></A>"#;
+        let node = Node::parse(xml).unwrap();
+
+        assert_eq!("1 & 2", node.attributes[0].1);
+        assert_eq!(2, node.children.len());
+        assert_eq!(xml, node.to_string());
+    }
+
+    #[test]
+    fn positions() {
+        let position = Position::after(["A", "B", "B", "C"].into_iter());
+
+        assert_eq!(3, position.index(["A", "B", "B", "D"].into_iter()));
+        assert_eq!(2, position.index(["A", "B", "D"].into_iter()));
+        assert_eq!(4, position.index(["A", "B", "B", "C", "D"].into_iter()));
+        assert_eq!(0, position.index(["D"].into_iter()));
+    }
+
+    #[test]
+    fn names_of_fields() {
+        assert_eq!(&["currency", "type", "value"], field_names::<Fee>());
+    }
+}
@@ -0,0 +1,525 @@
+use std::{io::BufRead, ops::Deref, sync::Arc};
+
+use encoding_rs::Encoding;
+use fast_xml::{
+    events::{BytesStart, Event},
+    Reader, Writer,
+};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    error::Error,
+    model::{self, Athlete, Club, Meet, Relay},
+    serialization::encoding,
+    Result,
+};
+
+/// The meet the athletes read by a [`LenexReader`] take part in.
+///
+/// Holds what the `MEET` element declares before its clubs, such as its sessions and events,
+/// but none of its clubs. Cloning it is cheap.
+#[derive(Debug, Clone)]
+pub struct MeetContext {
+    meet: Arc<Meet>,
+}
+
+impl MeetContext {
+    pub fn meet(&self) -> &Meet {
+        &self.meet
+    }
+}
+
+impl Deref for MeetContext {
+    type Target = Meet;
+
+    fn deref(&self) -> &Self::Target {
+        &self.meet
+    }
+}
+
+/// The elements of a club read one at a time.
+struct Items {
+    element: &'static [u8],
+    collection: &'static [u8],
+
+    /// The collection of the other elements of a club, left empty.
+    other: &'static [u8],
+}
+
+const ATHLETES: Items = Items {
+    element: b"ATHLETE",
+    collection: b"ATHLETES",
+    other: b"RELAYS",
+};
+
+const RELAYS: Items = Items {
+    element: b"RELAY",
+    collection: b"RELAYS",
+    other: b"ATHLETES",
+};
+
+/// The collections a meet and a club cannot be read without, left empty when the document
+/// does not hold them before their clubs or items.
+const MEET_COLLECTIONS: &[&[u8]] = &[b"SESSIONS"];
+const CLUB_COLLECTIONS: &[&[u8]] = &[b"ATHLETES"];
+
+/// Reads the athletes of a document one at a time, deserializing only the current meet, club
+/// and athlete.
+///
+/// The [`MeetContext`] is read from the children of the meet before its `CLUBS` element, and
+/// each club from its children before its `ATHLETES`, so that nothing more than the current
+/// athlete is kept in memory. Children following them, such as fees written after the clubs,
+/// are skipped.
+///
+/// Clubs come without their athletes and relays, athletes with their entries and results.
+/// Relays are read by [`LenexReader::relays`] instead. Record lists and time standard lists
+/// are skipped.
+pub struct LenexReader<R: BufRead> {
+    reader: Reader<R>,
+    buffer: Vec<u8>,
+    encoding: Option<&'static Encoding>,
+    meet: Option<MeetContext>,
+    club: Option<Club>,
+    done: bool,
+}
+
+impl<R: BufRead> LenexReader<R> {
+    pub fn new(reader: R) -> Self {
+        let mut reader = Reader::from_reader(reader);
+        reader.trim_text(true);
+
+        Self {
+            reader,
+            buffer: Vec::new(),
+            encoding: None,
+            meet: None,
+            club: None,
+            done: false,
+        }
+    }
+
+    /// Reads the results of every athlete one at a time, the athletes coming without theirs.
+    pub fn results(
+        self,
+    ) -> impl Iterator<Item = Result<(MeetContext, Club, Athlete, model::Result)>> {
+        self.flat_map(|item| match item {
+            Ok((meet, club, mut athlete)) => std::mem::take(athlete.results_mut())
+                .into_iter()
+                .map(|result| Ok((meet.clone(), club.clone(), athlete.clone(), result)))
+                .collect(),
+            Err(e) => vec![Err(e)],
+        })
+    }
+
+    /// Reads the relays of the document one at a time instead of its athletes, with their
+    /// entries, results and positions.
+    ///
+    /// Clubs are read from their children before their `RELAYS` element.
+    pub fn relays(mut self) -> impl Iterator<Item = Result<(MeetContext, Club, Relay)>> {
+        std::iter::from_fn(move || self.next_item(&RELAYS))
+    }
+
+    /// Reads the results of every relay one at a time, the relays coming without theirs.
+    pub fn relay_results(
+        self,
+    ) -> impl Iterator<Item = Result<(MeetContext, Club, Relay, model::Result)>> {
+        self.relays().flat_map(|item| match item {
+            Ok((meet, club, mut relay)) => std::mem::take(relay.results_mut())
+                .into_iter()
+                .map(|result| Ok((meet.clone(), club.clone(), relay.clone(), result)))
+                .collect(),
+            Err(e) => vec![Err(e)],
+        })
+    }
+
+    fn next_item<T: DeserializeOwned>(
+        &mut self,
+        items: &Items,
+    ) -> Option<Result<(MeetContext, Club, T)>> {
+        if self.done {
+            return None;
+        }
+
+        let item = self.read_item(items).transpose();
+        self.done = !matches!(item, Some(Ok(_)));
+
+        item
+    }
+
+    fn read_item<T: DeserializeOwned>(
+        &mut self,
+        items: &Items,
+    ) -> Result<Option<(MeetContext, Club, T)>> {
+        loop {
+            match self.next_event()? {
+                Event::Decl(declaration) => {
+                    if let Some(label) = declaration.encoding() {
+                        let label = label?;
+                        self.encoding = Some(Encoding::for_label(&label).ok_or_else(|| {
+                            Error::UnsupportedEncoding(String::from_utf8_lossy(&label).into())
+                        })?);
+                    }
+                }
+                Event::Start(start) => match start.name() {
+                    b"LENEX" | b"MEETS" | b"CLUBS" => {}
+                    name if name == items.collection => {}
+                    b"MEET" => {
+                        let (xml, clubs) =
+                            self.record_head(start, b"CLUBS", None, MEET_COLLECTIONS)?;
+                        self.meet = match clubs {
+                            true => Some(MeetContext {
+                                meet: Arc::new(self.deserialize(&xml)?),
+                            }),
+                            false => None,
+                        };
+                    }
+                    b"CLUB" if self.meet.is_some() => {
+                        let (xml, items) = self.record_head(
+                            start,
+                            items.collection,
+                            Some(items.other),
+                            CLUB_COLLECTIONS,
+                        )?;
+                        self.club = match items {
+                            true => Some(self.deserialize(&xml)?),
+                            false => None,
+                        };
+                    }
+                    name if name == items.element => {
+                        let xml = self.record(start)?;
+                        if let Some(item) = self.item(&xml)? {
+                            return Ok(Some(item));
+                        }
+                    }
+                    _ => self.skip(&start)?,
+                },
+                Event::Empty(start) if start.name() == items.element => {
+                    let mut writer = Writer::new(Vec::new());
+                    writer.write_event(Event::Empty(start))?;
+                    let xml = writer.into_inner();
+                    if let Some(item) = self.item(&xml)? {
+                        return Ok(Some(item));
+                    }
+                }
+                Event::End(end) => match end.name() {
+                    b"MEET" => self.meet = None,
+                    b"CLUB" => self.club = None,
+                    _ => {}
+                },
+                Event::Eof => return Ok(None),
+                _ => {}
+            }
+        }
+    }
+
+    fn next_event(&mut self) -> Result<Event<'static>> {
+        self.buffer.clear();
+
+        Ok(self.reader.read_event(&mut self.buffer)?.into_owned())
+    }
+
+    /// Copies the element started by `start` until its end.
+    fn record(&mut self, start: BytesStart<'static>) -> Result<Vec<u8>> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_event(Event::Start(start.to_borrowed()))?;
+
+        let mut depth = 0usize;
+        loop {
+            let event = self.next_event()?;
+            writer.write_event(&event)?;
+
+            match event {
+                Event::Start(_) => depth += 1,
+                Event::End(_) if depth == 0 => return Ok(writer.into_inner()),
+                Event::End(_) => depth -= 1,
+                Event::Eof => return Err(unexpected_eof(&start)),
+                _ => {}
+            }
+        }
+    }
+
+    /// Copies the element started by `start` until its child named `until`, which is left
+    /// empty and unread, or until its end. Its child named `empty` is left empty, as are the
+    /// `required` collections it does not hold. Also returns whether `until` was reached.
+    fn record_head(
+        &mut self,
+        start: BytesStart<'static>,
+        until: &[u8],
+        empty: Option<&[u8]>,
+        required: &[&[u8]],
+    ) -> Result<(Vec<u8>, bool)> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_event(Event::Start(start.to_borrowed()))?;
+
+        let mut missing = required.to_vec();
+        let reached = loop {
+            let event = self.next_event()?;
+
+            match &event {
+                Event::Start(child) | Event::Empty(child) => {
+                    missing.retain(|name| *name != child.name());
+                    if child.name() == until {
+                        break true;
+                    }
+                }
+                Event::End(_) => break false,
+                Event::Eof => return Err(unexpected_eof(&start)),
+                _ => {}
+            }
+
+            match event {
+                Event::Start(child) if Some(child.name()) == empty => {
+                    writer.write_event(Event::Empty(child.to_borrowed()))?;
+                    self.skip(&child)?;
+                }
+                Event::Start(child) => {
+                    let xml = self.record(child)?;
+                    writer.inner().extend_from_slice(&xml);
+                }
+                event => writer.write_event(event)?,
+            }
+        };
+
+        for name in missing.into_iter().chain(reached.then_some(until)) {
+            writer.write_event(Event::Empty(BytesStart::borrowed_name(name)))?;
+        }
+        writer.write_event(Event::End(start.to_end()))?;
+
+        Ok((writer.into_inner(), reached))
+    }
+
+    /// Reads past the end of the element started by `start`.
+    fn skip(&mut self, start: &BytesStart) -> Result<()> {
+        let mut depth = 0usize;
+        loop {
+            match self.next_event()? {
+                Event::Start(_) => depth += 1,
+                Event::End(_) if depth == 0 => return Ok(()),
+                Event::End(_) => depth -= 1,
+                Event::Eof => return Err(unexpected_eof(start)),
+                _ => {}
+            }
+        }
+    }
+
+    fn item<T: DeserializeOwned>(&self, xml: &[u8]) -> Result<Option<(MeetContext, Club, T)>> {
+        let item = self.deserialize(xml)?;
+
+        Ok(match (&self.meet, &self.club) {
+            (Some(meet), Some(club)) => Some((meet.clone(), club.clone(), item)),
+            _ => None,
+        })
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, xml: &[u8]) -> Result<T> {
+        let xml = encoding::decode_fragment(xml, self.encoding)?;
+
+        fast_xml::de::from_str(&xml).map_err(Error::Serialize)
+    }
+}
+
+impl<R: BufRead> Iterator for LenexReader<R> {
+    type Item = Result<(MeetContext, Club, Athlete)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_item(&ATHLETES)
+    }
+}
+
+fn unexpected_eof(start: &BytesStart) -> Error {
+    fast_xml::Error::UnexpectedEof(String::from_utf8_lossy(start.name()).into()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::model::{Entry, Event, Fee, FeeType, Gender, Lenex, Session, SwimStyle, SwimTime};
+
+    fn document() -> Lenex {
+        let mut lenex = Lenex::new();
+        let mut meet = Meet::new(
+            "Championships".into(),
+            "CAN".into(),
+            "Montréal".into(),
+            vec![Session::new(
+                1,
+                NaiveDate::from_ymd_opt(2024, 3, 2).unwrap(),
+                vec![Event::new(1, 1, SwimStyle::default())],
+            )],
+        );
+
+        for (id, name) in [(1, "CAMO"), (2, "Club Aquatique")] {
+            let mut club = Club::new(id, name.into());
+            for number in 1..=2 {
+                let mut athlete = Athlete::new(
+                    id * 10 + number,
+                    format!("Athlete {number}"),
+                    name.into(),
+                    Gender::Female,
+                    NaiveDate::from_ymd_opt(2010, 4, 30).unwrap().into(),
+                );
+                athlete.add_entry(Entry::new(1));
+                let mut result = model::Result::new(id * 10 + number, 1);
+                result.with_swim_time(SwimTime::Time(3000 + number));
+                athlete.add_result(result);
+                club.add_athlete(athlete);
+            }
+            let mut relay = Relay::new(1, Gender::Female);
+            let mut result = model::Result::new(id * 10, 1);
+            result.with_swim_time(SwimTime::Time(12000 + id));
+            relay.add_result(result);
+            club.add_relay(relay);
+            meet.add_club(club);
+        }
+        lenex.meets.push(meet);
+
+        lenex
+    }
+
+    #[test]
+    fn read_athletes() {
+        let lenex = document();
+        let xml = lenex.xml().unwrap();
+
+        let athletes = LenexReader::new(xml.as_bytes())
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(4, athletes.len());
+
+        let (meet, club, athlete) = &athletes[2];
+        assert_eq!("Championships", meet.name());
+        assert_eq!(1, meet.sessions()[0].events().len());
+        assert!(meet.clubs().is_empty());
+        assert_eq!("Club Aquatique", club.name());
+        assert!(club.athletes().is_empty());
+        assert!(club.relays().is_empty());
+        assert_eq!(&lenex.meets[0].clubs()[1].athletes()[0], athlete);
+    }
+
+    #[test]
+    fn read_results() {
+        let xml = document().xml().unwrap();
+
+        let results = LenexReader::new(xml.as_bytes())
+            .results()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(4, results.len());
+
+        let (_, club, athlete, result) = &results[1];
+        assert_eq!(1, club.id());
+        assert_eq!(12, athlete.id());
+        assert!(athlete.results().is_empty());
+        assert_eq!(Some(SwimTime::Time(3002)), result.swim_time());
+    }
+
+    #[test]
+    fn read_latin1() {
+        let mut lenex = document();
+        lenex.meets[0].clubs_mut()[0].athletes_mut().truncate(1);
+        let bytes = lenex.xml_with_encoding("ISO-8859-1").unwrap();
+
+        let mut reader = LenexReader::new(bytes.as_slice());
+        let (meet, _, _) = reader.next().unwrap().unwrap();
+        assert_eq!("Montréal", meet.city());
+        assert_eq!(2, reader.count());
+    }
+
+    #[test]
+    fn read_invalid_athlete() {
+        let xml =
+            document()
+                .xml()
+                .unwrap()
+                .replacen("athleteid=\"21\"", "athleteid=\"twenty-one\"", 1);
+
+        let mut reader = LenexReader::new(xml.as_bytes());
+        assert_eq!(2, reader.by_ref().take_while(|item| item.is_ok()).count());
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn read_clubs_first() {
+        let mut lenex = document();
+        lenex.meets[0].add_fee(Fee::new(FeeType::Athlete, 1500));
+        let mut xml = lenex.xml().unwrap();
+
+        let clubs = xml.find("<CLUBS>").unwrap()..xml.find("</CLUBS>").unwrap() + 8;
+        let clubs: String = xml.drain(clubs).collect();
+        let meet = xml.find("<MEET ").unwrap();
+        let children = meet + xml[meet..].find('>').unwrap() + 1;
+        xml.insert_str(children, &clubs);
+        assert!(xml.contains("<CLUBS><CLUB"));
+        assert!(xml.contains("</CLUBS><FEES>"));
+
+        let athletes = LenexReader::new(xml.as_bytes())
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(4, athletes.len());
+
+        // The sessions and fees following the clubs are not read.
+        let (meet, _, athlete) = &athletes[3];
+        assert!(meet.sessions().is_empty());
+        assert!(meet.fees().is_empty());
+        assert_eq!(&lenex.meets[0].clubs()[1].athletes()[1], athlete);
+    }
+
+    #[test]
+    fn read_large_document() {
+        let xml = document().xml().unwrap();
+        let start = xml.find("<ATHLETE ").unwrap();
+        let athlete = &xml[start..xml.find("</ATHLETE>").unwrap() + 10];
+        let count = 20_000;
+        let xml = format!(
+            "{}{}{}",
+            &xml[..start],
+            athlete.repeat(count),
+            &xml[start..]
+        );
+        assert!(xml.len() > 4 << 20);
+
+        let mut reader = LenexReader::new(xml.as_bytes());
+        for read in 1..=count {
+            assert!(reader.next().unwrap().is_ok());
+
+            // Nothing is read past the current athlete, and only one element is held at a time.
+            assert_eq!(
+                start + read * athlete.len(),
+                reader.reader.buffer_position()
+            );
+            assert!(reader.buffer.capacity() < 1024);
+        }
+        assert_eq!(4, reader.count());
+    }
+
+    #[test]
+    fn read_relays() {
+        let xml = document().xml().unwrap();
+
+        let relays = LenexReader::new(xml.as_bytes())
+            .relays()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(2, relays.len());
+
+        let (meet, club, relay) = &relays[1];
+        assert_eq!(1, meet.sessions().len());
+        assert_eq!(2, club.id());
+        assert!(club.athletes().is_empty());
+        assert!(club.relays().is_empty());
+        assert_eq!(1, relay.results().len());
+
+        let results = LenexReader::new(xml.as_bytes())
+            .relay_results()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(2, results.len());
+
+        let (_, club, relay, result) = &results[0];
+        assert_eq!(1, club.id());
+        assert!(relay.results().is_empty());
+        assert_eq!(Some(SwimTime::Time(12001)), result.swim_time());
+    }
+}